@@ -8,7 +8,10 @@
 
 use bevy::{
     a11y::AccessibilityNode,
-    ecs::{component::HookContext, system::SystemId, world::DeferredWorld},
+    ecs::{
+        component::HookContext, relationship::RelatedSpawner, spawn::SpawnWith, system::SystemId,
+        world::DeferredWorld,
+    },
     input_focus::{
         tab_navigation::{TabGroup, TabIndex, TabNavigationPlugin},
         InputDispatchPlugin, InputFocus, InputFocusVisible,
@@ -19,8 +22,9 @@ use bevy::{
     winit::{cursor::CursorIcon, WinitSettings},
 };
 use bevy_core_widgets::{
-    hover::Hovering, ButtonClicked, ButtonPressed, Checked, CoreButton, CoreCheckbox, CoreRadio,
-    CoreRadioGroup, CoreSlider, CoreWidgetsPlugin, InteractionDisabled, SliderDragState,
+    hover::Hovering, ButtonClicked, ButtonPressed, Checked, CoreButton, CoreCheckbox,
+    CoreDisclosureToggle, CoreRadio, CoreRadioGroup, CoreSlider, CoreSpinBox, CoreSpinBoxButton,
+    CoreWidgetsPlugin, Expanded, InteractionDisabled, Orientation, SliderDragState, SliderSnap,
     ValueChange,
 };
 
@@ -42,6 +46,8 @@ fn main() {
                 update_checkbox_colors,
                 update_radio_colors,
                 update_slider_thumb,
+                update_spinbox_text,
+                update_disclosure_indicator,
                 close_on_esc,
             ),
         )
@@ -52,8 +58,8 @@ fn setup_view_root(mut commands: Commands) {
     let camera = commands.spawn((Camera::default(), Camera2d)).id();
 
     // Demonstration click handler.
-    let on_click = commands.register_system(|| {
-        info!("Button on_click handler called!");
+    let on_click = commands.register_system(|In(click): In<ButtonClicked>| {
+        info!("Button on_click handler called with {:?}", click.input);
     });
 
     commands.spawn((
@@ -81,8 +87,10 @@ fn setup_view_root(mut commands: Commands) {
             Spawn(radio_demo()),
             Spawn(Text::new("Slider")),
             Spawn(slider_demo()),
-            // Spawn(Text::new("SpinBox")),
-            // Spawn(Text::new("DisclosureToggle")),
+            Spawn(Text::new("SpinBox")),
+            Spawn(spinbox_demo()),
+            Spawn(Text::new("DisclosureToggle")),
+            Spawn(disclosure_demo()),
         )),
     ));
 
@@ -106,7 +114,7 @@ fn setup_view_root(mut commands: Commands) {
             trigger.propagate(false);
             if q_checkbox.contains(trigger.target()) {
                 // Update checkbox state from event.
-                let is_checked = trigger.event().0;
+                let is_checked = trigger.event().value;
                 commands
                     .entity(trigger.target())
                     .insert(Checked(is_checked));
@@ -115,6 +123,23 @@ fn setup_view_root(mut commands: Commands) {
         },
     );
 
+    // Observer for disclosure toggles that don't have an on_change handler.
+    commands.add_observer(
+        |mut trigger: Trigger<ValueChange<bool>>,
+         q_toggle: Query<&CoreDisclosureToggle>,
+         mut commands: Commands| {
+            trigger.propagate(false);
+            if q_toggle.contains(trigger.target()) {
+                // Update disclosure state from event.
+                let is_expanded = trigger.event().value;
+                commands
+                    .entity(trigger.target())
+                    .insert(Expanded(is_expanded));
+                info!("New disclosure state: {:?}", is_expanded);
+            }
+        },
+    );
+
     // Observer for radio buttons.
     commands.add_observer(
         |mut trigger: Trigger<ValueChange<Entity>>,
@@ -124,7 +149,7 @@ fn setup_view_root(mut commands: Commands) {
             trigger.propagate(false);
             if q_radio_group.contains(trigger.target()) {
                 // Update checkbox state from event.
-                let selected_entity = trigger.event().0;
+                let selected_entity = trigger.event().value;
                 let (child_of, radio_value) = q_radio.get(selected_entity).unwrap();
                 // Mutual exclusion logic
                 let group_children = q_radio_group.get(child_of.parent()).unwrap();
@@ -146,11 +171,23 @@ fn setup_view_root(mut commands: Commands) {
             trigger.propagate(false);
             if let Ok(mut slider) = q_slider.get_mut(trigger.target()) {
                 // Update slider state from event.
-                slider.set_value(trigger.event().0);
+                slider.set_value(trigger.event().value);
                 info!("New slider state: {:?}", slider.value());
             }
         },
     );
+
+    // Observer for spin boxes that don't have an on_change handler.
+    commands.add_observer(
+        |mut trigger: Trigger<ValueChange<f32>>, mut q_spinbox: Query<&mut CoreSpinBox>| {
+            trigger.propagate(false);
+            if let Ok(mut spinbox) = q_spinbox.get_mut(trigger.target()) {
+                // Update spin box state from event.
+                spinbox.set_value(trigger.event().value);
+                info!("New spin box state: {:?}", spinbox.value());
+            }
+        },
+    );
 }
 
 pub fn close_on_esc(input: Res<ButtonInput<KeyCode>>, mut exit: EventWriter<AppExit>) {
@@ -203,7 +240,7 @@ fn update_focus_rect(
 }
 
 /// Create a row of demo buttons
-fn buttons_demo(on_click: SystemId) -> impl Bundle {
+fn buttons_demo(on_click: SystemId<In<ButtonClicked>>) -> impl Bundle {
     (
         Node {
             display: ui::Display::Flex,
@@ -229,7 +266,11 @@ struct DemoButton {
 }
 
 /// Create a demo button
-fn button(caption: &str, variant: ButtonVariant, on_click: Option<SystemId>) -> impl Bundle {
+fn button(
+    caption: &str,
+    variant: ButtonVariant,
+    on_click: Option<SystemId<In<ButtonClicked>>>,
+) -> impl Bundle {
     (
         Node {
             display: ui::Display::Flex,
@@ -247,7 +288,10 @@ fn button(caption: &str, variant: ButtonVariant, on_click: Option<SystemId>) ->
         Hovering::default(),
         CursorIcon::System(SystemCursorIcon::Pointer),
         DemoButton { variant },
-        CoreButton { on_click },
+        CoreButton {
+            on_click,
+            ..default()
+        },
         AccessibleName(caption.to_string()),
         TabIndex(0),
         children![(
@@ -319,7 +363,11 @@ fn checkbox_demo() -> impl Bundle {
 struct DemoCheckbox;
 
 /// Create a demo checkbox
-fn checkbox(caption: &str, checked: bool, on_change: Option<SystemId<In<bool>>>) -> impl Bundle {
+fn checkbox(
+    caption: &str,
+    checked: bool,
+    on_change: Option<SystemId<In<ValueChange<bool>>>>,
+) -> impl Bundle {
     (
         Node {
             display: ui::Display::Flex,
@@ -609,6 +657,7 @@ fn slider_demo() -> impl Bundle {
         Children::spawn((
             Spawn(slider("Volume", 0.0, 100.0, 0.0, None)),
             Spawn(slider("Difficulty", 0.0, 10.0, 5.0, None)),
+            Spawn(vertical_slider("Gain", 0.0, 10.0, 3.0, None)),
         )),
     )
 }
@@ -622,7 +671,7 @@ fn slider(
     min: f32,
     max: f32,
     value: f32,
-    on_change: Option<SystemId<In<f32>>>,
+    on_change: Option<SystemId<In<ValueChange<f32>>>>,
 ) -> impl Bundle {
     (
         Node {
@@ -647,6 +696,7 @@ fn slider(
             value,
             on_change,
             thumb_size: 12.0,
+            snap: SliderSnap::Increment,
             ..default()
         },
         TabIndex(0),
@@ -691,6 +741,80 @@ fn slider(
     )
 }
 
+/// Create a demo slider which is oriented vertically
+fn vertical_slider(
+    label: &str,
+    min: f32,
+    max: f32,
+    value: f32,
+    on_change: Option<SystemId<In<ValueChange<f32>>>>,
+) -> impl Bundle {
+    (
+        Node {
+            display: ui::Display::Flex,
+            flex_direction: ui::FlexDirection::Row,
+            justify_content: ui::JustifyContent::Center,
+            align_items: ui::AlignItems::Stretch,
+            width: ui::Val::Px(12.0),
+            height: ui::Val::Px(80.0),
+            ..default()
+        },
+        Name::new("Slider"),
+        AccessibleName(label.to_string()),
+        Hovering::default(),
+        CursorIcon::System(SystemCursorIcon::Pointer),
+        DemoSlider,
+        CoreSlider {
+            max,
+            min,
+            value,
+            on_change,
+            thumb_size: 12.0,
+            snap: SliderSnap::Increment,
+            orientation: Orientation::Vertical,
+            ..default()
+        },
+        TabIndex(0),
+        Children::spawn((
+            // Slider background rail
+            Spawn((
+                Node {
+                    width: ui::Val::Px(6.0),
+                    ..default()
+                },
+                BackgroundColor(colors::U3.into()),
+                BorderRadius::all(ui::Val::Px(3.0)),
+            )),
+            // Invisible track to allow absolute placement of thumb entity. This is shorter than
+            // the actual slider, so that the thumb can be positioned using percentages.
+            Spawn((
+                Node {
+                    display: ui::Display::Flex,
+                    position_type: ui::PositionType::Absolute,
+                    left: ui::Val::Px(0.0),
+                    right: ui::Val::Px(0.0),
+                    top: ui::Val::Px(12.0), // Track is short by 12px to accommodate the thumb
+                    bottom: ui::Val::Px(0.0),
+                    ..default()
+                },
+                children![(
+                    // Thumb
+                    Node {
+                        display: ui::Display::Flex,
+                        width: ui::Val::Px(12.0),
+                        height: ui::Val::Px(12.0),
+                        position_type: ui::PositionType::Absolute,
+                        bottom: ui::Val::Percent(50.0), // This will be updated by the slider's value
+                        ..default()
+                    },
+                    BorderRadius::all(ui::Val::Px(6.0)),
+                    BackgroundColor(colors::PRIMARY.into()),
+                )],
+            )),
+        )),
+    )
+}
+
 // Update the button's background color.
 #[allow(clippy::type_complexity)]
 fn update_slider_thumb(
@@ -750,8 +874,224 @@ fn update_slider_thumb(
         }
 
         let thumb_position = ui::Val::Percent(slider_state.thumb_position() * 100.0);
-        if node.left != thumb_position {
-            node.left = thumb_position;
+        match slider_state.orientation {
+            Orientation::Horizontal => {
+                if node.left != thumb_position {
+                    node.left = thumb_position;
+                }
+            }
+            Orientation::Vertical => {
+                if node.bottom != thumb_position {
+                    node.bottom = thumb_position;
+                }
+            }
+        }
+    }
+}
+
+/// Create a row of demo spin boxes
+fn spinbox_demo() -> impl Bundle {
+    (
+        Node {
+            display: ui::Display::Flex,
+            flex_direction: ui::FlexDirection::Row,
+            align_items: ui::AlignItems::Center,
+            padding: ui::UiRect::axes(ui::Val::Px(12.0), ui::Val::Px(0.0)),
+            column_gap: ui::Val::Px(12.0),
+            ..default()
+        },
+        Children::spawn((
+            Spawn(spinbox("Quantity", 0.0, 99.0, 1.0)),
+            Spawn(spinbox("Temperature", -40.0, 40.0, 20.0)),
+        )),
+    )
+}
+
+#[derive(Component, Default)]
+struct DemoSpinBoxValue;
+
+/// Create a demo spin box
+fn spinbox(label: &str, min: f32, max: f32, value: f32) -> impl Bundle {
+    (
+        Node {
+            display: ui::Display::Flex,
+            flex_direction: ui::FlexDirection::Row,
+            align_items: ui::AlignItems::Center,
+            column_gap: ui::Val::Px(4.0),
+            ..default()
+        },
+        Name::new("SpinBox"),
+        AccessibleName(label.to_string()),
+        CoreSpinBox {
+            min,
+            max,
+            value,
+            ..default()
+        },
+        TabIndex(0),
+        Children::spawn((
+            Spawn(spinbox_button("-", CoreSpinBoxButton::Decrement)),
+            Spawn((
+                Node {
+                    min_width: ui::Val::Px(32.0),
+                    justify_content: ui::JustifyContent::Center,
+                    ..default()
+                },
+                children![(
+                    Text::new(format!("{value}")),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    DemoSpinBoxValue,
+                )],
+            )),
+            Spawn(spinbox_button("+", CoreSpinBoxButton::Increment)),
+        )),
+    )
+}
+
+/// Create a step button for a demo spin box
+fn spinbox_button(caption: &str, step: CoreSpinBoxButton) -> impl Bundle {
+    (
+        Node {
+            display: ui::Display::Flex,
+            justify_content: ui::JustifyContent::Center,
+            align_items: ui::AlignItems::Center,
+            width: ui::Val::Px(24.0),
+            height: ui::Val::Px(24.0),
+            ..default()
+        },
+        BorderRadius::all(ui::Val::Px(4.0)),
+        Name::new("SpinBoxButton"),
+        Hovering::default(),
+        CursorIcon::System(SystemCursorIcon::Pointer),
+        DemoButton::default(),
+        step,
+        children![(
+            Text::new(caption),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            }
+        )],
+    )
+}
+
+// Update the text displaying the spin box's value.
+fn update_spinbox_text(
+    q_spinbox: Query<(Entity, &CoreSpinBox), Changed<CoreSpinBox>>,
+    q_children: Query<&Children>,
+    mut q_text: Query<&mut Text, With<DemoSpinBoxValue>>,
+) {
+    for (spinbox_id, spinbox) in q_spinbox.iter() {
+        for descendant in q_children.iter_descendants(spinbox_id) {
+            if let Ok(mut text) = q_text.get_mut(descendant) {
+                text.0 = format!("{}", spinbox.value());
+            }
+        }
+    }
+}
+
+/// Create a disclosure toggle which shows and hides a column of text
+fn disclosure_demo() -> impl Bundle {
+    (
+        Node {
+            display: ui::Display::Flex,
+            flex_direction: ui::FlexDirection::Column,
+            align_items: ui::AlignItems::Start,
+            padding: ui::UiRect::axes(ui::Val::Px(12.0), ui::Val::Px(0.0)),
+            row_gap: ui::Val::Px(6.0),
+            ..default()
+        },
+        Children::spawn(SpawnWith(|parent: &mut RelatedSpawner<ChildOf>| {
+            // Note that we're using `SpawnWith` here because we need to get the entity id of the
+            // content in order to set the content of the toggle.
+            let toggle_id = parent.spawn(disclosure_toggle("Advanced Options")).id();
+            let content_id = parent
+                .spawn((
+                    Node {
+                        display: ui::Display::Flex,
+                        flex_direction: ui::FlexDirection::Column,
+                        padding: ui::UiRect::left(ui::Val::Px(20.0)),
+                        row_gap: ui::Val::Px(4.0),
+                        ..default()
+                    },
+                    Children::spawn((
+                        Spawn(text_label("Frobnication Level")),
+                        Spawn(text_label("Reticulate Splines")),
+                    )),
+                ))
+                .id();
+            parent
+                .world_mut()
+                .entity_mut(toggle_id)
+                .insert(CoreDisclosureToggle {
+                    on_change: None,
+                    content: Some(content_id),
+                });
+        })),
+    )
+}
+
+#[derive(Component, Default)]
+struct DemoDisclosureIndicator;
+
+/// Create a demo disclosure toggle. The toggle starts out collapsed.
+fn disclosure_toggle(caption: &str) -> impl Bundle {
+    (
+        Node {
+            display: ui::Display::Flex,
+            flex_direction: ui::FlexDirection::Row,
+            align_items: ui::AlignItems::Center,
+            column_gap: ui::Val::Px(4.0),
+            ..default()
+        },
+        Name::new("DisclosureToggle"),
+        AccessibleName(caption.to_string()),
+        Hovering::default(),
+        CursorIcon::System(SystemCursorIcon::Pointer),
+        Expanded(false),
+        TabIndex(0),
+        Children::spawn((
+            Spawn((
+                Node {
+                    width: ui::Val::Px(12.0),
+                    ..default()
+                },
+                Text::new("+"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                DemoDisclosureIndicator,
+            )),
+            Spawn(text_label(caption)),
+        )),
+    )
+}
+
+/// Create a text label
+fn text_label(caption: &str) -> impl Bundle {
+    (
+        Text::new(caption),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+    )
+}
+
+// Update the indicator which shows whether the disclosure toggle is expanded.
+fn update_disclosure_indicator(
+    q_toggle: Query<(&Expanded, &Children), Changed<Expanded>>,
+    mut q_indicator: Query<&mut Text, With<DemoDisclosureIndicator>>,
+) {
+    for (Expanded(is_expanded), children) in q_toggle.iter() {
+        for child in children.iter() {
+            if let Ok(mut text) = q_indicator.get_mut(child) {
+                text.0 = if *is_expanded { "-" } else { "+" }.to_string();
+            }
         }
     }
 }
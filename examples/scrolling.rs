@@ -20,7 +20,8 @@ use bevy::{
     ui,
 };
 use bevy_core_widgets::{
-    hover::Hovering, CoreScrollbar, CoreScrollbarThumb, CoreSlider, CoreWidgetsPlugin, Orientation,
+    hover::Hovering, CoreScrollArea, CoreScrollbar, CoreScrollbarStepButton, CoreScrollbarThumb,
+    CoreSlider, CoreWidgetsPlugin, Orientation, SmoothScroll, StepDirection, TrackClick,
     ValueChange,
 };
 
@@ -68,7 +69,7 @@ fn setup_view_root(mut commands: Commands) {
             trigger.propagate(false);
             if let Ok(mut slider) = q_slider.get_mut(trigger.target()) {
                 // Update slider state from event.
-                slider.set_value(trigger.event().0);
+                slider.set_value(trigger.event().value);
                 info!("New slider state: {:?}", slider.value());
             }
         },
@@ -165,6 +166,9 @@ fn scroll_area_demo() -> impl Bundle {
                         ..default()
                     },
                     BackgroundColor(colors::U3.into()),
+                    CoreScrollArea::default(),
+                    SmoothScroll::default(),
+                    TabIndex(0),
                     ScrollPosition {
                         offset_x: 0.0,
                         offset_y: 10.0,
@@ -197,20 +201,23 @@ fn scroll_area_demo() -> impl Bundle {
                 },
                 Hovering(false),
                 CoreScrollbar {
-                    orientation: Orientation::Vertical,
-                    target: scroll_area_id,
-                    min_thumb_size: 8.0,
+                    track_click: TrackClick::Page,
+                    ..CoreScrollbar::new(scroll_area_id, Orientation::Vertical, 8.0)
                 },
-                Children::spawn(Spawn((
-                    Node {
-                        position_type: ui::PositionType::Absolute,
-                        ..default()
-                    },
-                    Hovering(false),
-                    BackgroundColor(colors::U4.into()),
-                    BorderRadius::all(ui::Val::Px(4.0)),
-                    CoreScrollbarThumb,
-                ))),
+                Children::spawn((
+                    Spawn(step_button(StepDirection::Backward)),
+                    Spawn((
+                        Node {
+                            position_type: ui::PositionType::Absolute,
+                            ..default()
+                        },
+                        Hovering(false),
+                        BackgroundColor(colors::U4.into()),
+                        BorderRadius::all(ui::Val::Px(4.0)),
+                        CoreScrollbarThumb,
+                    )),
+                    Spawn(step_button(StepDirection::Forward)),
+                )),
             ));
 
             // Horizontal scrollbar
@@ -223,9 +230,8 @@ fn scroll_area_demo() -> impl Bundle {
                 },
                 Hovering(false),
                 CoreScrollbar {
-                    orientation: Orientation::Horizontal,
-                    target: scroll_area_id,
-                    min_thumb_size: 8.0,
+                    track_click: TrackClick::Page,
+                    ..CoreScrollbar::new(scroll_area_id, Orientation::Horizontal, 8.0)
                 },
                 Children::spawn(Spawn((
                     Node {
@@ -242,6 +248,30 @@ fn scroll_area_demo() -> impl Bundle {
     )
 }
 
+/// Create a step button for the vertical scrollbar
+fn step_button(direction: StepDirection) -> impl Bundle {
+    let (top, bottom) = match direction {
+        StepDirection::Backward => (ui::Val::Px(0.0), ui::Val::Auto),
+        StepDirection::Forward => (ui::Val::Auto, ui::Val::Px(0.0)),
+    };
+    (
+        Node {
+            position_type: ui::PositionType::Absolute,
+            left: ui::Val::Px(0.0),
+            right: ui::Val::Px(0.0),
+            top,
+            bottom,
+            height: ui::Val::Px(8.0),
+            ..default()
+        },
+        BackgroundColor(colors::U4.into()),
+        CoreScrollbarStepButton {
+            direction,
+            step: 20.0,
+        },
+    )
+}
+
 /// Create a list row
 fn text_row(caption: &str) -> impl Bundle {
     (
@@ -0,0 +1,50 @@
+use accesskit::{Action, ActionData, Role};
+use bevy::{
+    a11y::ActionRequest,
+    input_focus::{tab_navigation::TabIndex, InputFocus, InputFocusVisible},
+    prelude::*,
+};
+
+/// Create an accessibility node with the given role, which advertises support for the given
+/// actions to assistive technologies.
+pub(crate) fn accessible_node(role: Role, actions: &[Action]) -> accesskit::Node {
+    let mut node = accesskit::Node::new(role);
+    for action in actions {
+        node.add_action(*action);
+    }
+    node
+}
+
+/// Returns the entity which an action request is aimed at. Bevy derives accessibility node ids
+/// from entity ids, so this is the reverse of that mapping.
+pub(crate) fn action_target(request: &ActionRequest) -> Option<Entity> {
+    Entity::try_from_bits(request.target.0).ok()
+}
+
+/// Returns the numeric value which accompanies a `SetValue` action request, if any.
+pub(crate) fn action_numeric_value(request: &ActionRequest) -> Option<f64> {
+    match &request.data {
+        Some(ActionData::NumericValue(value)) => Some(*value),
+        Some(ActionData::Value(value)) => value.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Moves the input focus to the target of a `Focus` action request, if the target is focusable.
+/// Assistive technologies move focus without a pointer, so the focus ring is made visible.
+pub(crate) fn focus_on_action_request(
+    mut requests: EventReader<ActionRequest>,
+    q_focusable: Query<(), With<TabIndex>>,
+    mut focus: ResMut<InputFocus>,
+    mut focus_visible: ResMut<InputFocusVisible>,
+) {
+    for request in requests.read() {
+        if request.action != Action::Focus {
+            continue;
+        }
+        if let Some(target) = action_target(request).filter(|e| q_focusable.contains(*e)) {
+            focus.0 = Some(target);
+            focus_visible.0 = true;
+        }
+    }
+}
@@ -1,111 +1,547 @@
-use accesskit::Role;
+use accesskit::{Action, Role};
 use bevy::{
-    a11y::AccessibilityNode,
+    a11y::{AccessibilityNode, ActionRequest},
     ecs::system::SystemId,
-    input::keyboard::KeyboardInput,
-    input_focus::{FocusedInput, InputFocus, InputFocusVisible},
+    input::{keyboard::KeyboardInput, ButtonState},
+    input_focus::{tab_navigation::TabIndex, FocusedInput, InputFocus, InputFocusVisible},
     prelude::*,
 };
 
-use crate::{events::ButtonClicked, ButtonPressed, InteractionDisabled};
+use crate::{
+    accessibility::{accessible_node, action_target},
+    events::{ButtonClicked, ButtonDoubleClicked, ButtonLongPressed, InputSource, Modifiers},
+    repeat::{RepeatTimer, REPEAT_DELAY, REPEAT_INTERVAL},
+    ButtonArmed, ButtonPressed, InteractionDisabled,
+};
 
 /// Headless button widget. The `on_click` field is a system that will be run when the button
-/// is clicked, or when the Enter or Space key is pressed while the button is focused. If the
-/// `on_click` field is `None`, the button will emit a `ButtonClicked` event when clicked.
-#[derive(Component, Debug)]
-#[require(AccessibilityNode(accesskit::Node::new(Role::Button)))]
-#[require(ButtonPressed)]
+/// is clicked, or when the Enter or Space key is pressed while the button is focused. Enter
+/// clicks the button immediately, while Space presses the button down and clicks it when the key
+/// is released; moving the focus away while Space is held cancels the click. If the
+/// `on_click` field is `None`, the button will emit a `ButtonClicked` event when clicked. Either
+/// way, the [`ButtonClicked`] value describes the input which clicked the button. Assistive
+/// technologies can click the button with the `Click` action. Pressing the button with the
+/// pointer gives it focus if it is focusable, that is, if it has a [`TabIndex`].
+///
+/// Adding a [`ButtonAutoRepeat`] component makes the button click repeatedly while it is held.
+/// Long presses and double clicks are recognized when the button has a [`ButtonLongPress`] or
+/// [`ButtonDoubleClick`] component; they are reported to the `on_long_press` and
+/// `on_double_click` callbacks, or as [`ButtonLongPressed`] and [`ButtonDoubleClicked`] events.
+#[derive(Component, Debug, Default)]
+#[require(AccessibilityNode(accessible_node(Role::Button, &[Action::Click, Action::Focus])))]
+#[require(ButtonPressed, ButtonArmed, ButtonKeyState)]
 pub struct CoreButton {
-    pub on_click: Option<SystemId>,
+    pub on_click: Option<SystemId<In<ButtonClicked>>>,
+    /// Callback which is run when the button is long-pressed.
+    pub on_long_press: Option<SystemId>,
+    /// Callback which is run when the button is double-clicked.
+    pub on_double_click: Option<SystemId>,
+}
+
+/// Component used to track a keyboard press on a button.
+#[derive(Component, Default, Debug)]
+pub struct ButtonKeyState {
+    /// The key which is holding the button down, if it was pressed with the keyboard.
+    key: Option<KeyCode>,
+}
+
+/// Component which puts a [`CoreButton`] into auto-repeat mode, for controls such as zoom
+/// buttons and step arrows. The button clicks as soon as it is pressed, rather than when it is
+/// released, and then keeps clicking while it is held down with the pointer or with the Enter or
+/// Space key. Repeating stops when the button is released, when the pointer is cancelled, or
+/// when the button is disabled.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(ButtonRepeatState)]
+pub struct ButtonAutoRepeat {
+    /// Delay before the button starts repeating, in seconds.
+    pub delay: f32,
+    /// Interval between repeats, in seconds.
+    pub interval: f32,
+}
+
+impl Default for ButtonAutoRepeat {
+    fn default() -> Self {
+        Self {
+            delay: REPEAT_DELAY,
+            interval: REPEAT_INTERVAL,
+        }
+    }
+}
+
+/// Component used to manage the state of an auto-repeating button.
+#[derive(Component, Default, Debug)]
+pub struct ButtonRepeatState {
+    /// The input which is holding the button down.
+    input: Option<InputSource>,
+    timer: RepeatTimer,
+}
+
+/// Component which enables long presses on a [`CoreButton`], for touch-screen context actions
+/// and the like. Holding the pointer down on the button for `duration` seconds counts as a long
+/// press, and the release which follows doesn't count as a click.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(ButtonGestureState)]
+pub struct ButtonLongPress {
+    /// How long the button must be held down, in seconds.
+    pub duration: f32,
+}
+
+impl Default for ButtonLongPress {
+    fn default() -> Self {
+        Self { duration: 0.5 }
+    }
+}
+
+/// Component which enables double clicks on a [`CoreButton`]. Two pointer clicks count as a
+/// double click if the second one follows within `interval` seconds of the first, and within
+/// `distance` logical pixels of it. Both clicks are still reported as ordinary clicks.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(ButtonGestureState)]
+pub struct ButtonDoubleClick {
+    /// Maximum time between the two clicks, in seconds.
+    pub interval: f32,
+    /// Maximum distance between the two clicks, in logical pixels.
+    pub distance: f32,
+}
+
+impl Default for ButtonDoubleClick {
+    fn default() -> Self {
+        Self {
+            interval: 0.5,
+            distance: 4.0,
+        }
+    }
+}
+
+/// Component used to recognize long presses and double clicks on a button.
+#[derive(Component, Default, Debug)]
+pub struct ButtonGestureState {
+    /// Time at which the current pointer press started, if the button is pressed.
+    press_start: Option<f32>,
+    /// Whether the current press has been recognized as a long press.
+    long_pressed: bool,
+    /// Time and position of the previous click, if it could be the first half of a double click.
+    last_click: Option<(f32, Vec2)>,
 }
 
+/// Pass a click to the button's `on_click` callback, or emit it as a [`ButtonClicked`] event if
+/// there is none.
+fn click_button(commands: &mut Commands, bstate: &CoreButton, click: ButtonClicked) {
+    if let Some(on_click) = bstate.on_click {
+        commands.run_system_with(on_click, click);
+    } else {
+        let source = click.source;
+        commands.trigger_targets(click, source);
+    }
+}
+
+#[allow(clippy::type_complexity)]
 pub(crate) fn button_on_key_event(
     mut trigger: Trigger<FocusedInput<KeyboardInput>>,
-    q_state: Query<(&CoreButton, Has<InteractionDisabled>)>,
+    mut q_state: Query<(
+        &CoreButton,
+        &mut ButtonPressed,
+        &mut ButtonArmed,
+        &mut ButtonKeyState,
+        Option<&mut ButtonRepeatState>,
+        Has<InteractionDisabled>,
+    )>,
+    keys: Res<ButtonInput<KeyCode>>,
     mut commands: Commands,
 ) {
-    if let Ok((bstate, disabled)) = q_state.get(trigger.target()) {
-        if !disabled {
-            let event = &trigger.event().input;
-            if !event.repeat
-                && (event.key_code == KeyCode::Enter || event.key_code == KeyCode::Space)
-            {
-                if let Some(on_click) = bstate.on_click {
-                    trigger.propagate(false);
-                    commands.run_system(on_click);
-                } else {
-                    commands.trigger_targets(ButtonClicked, trigger.target());
-                }
+    let button_id = trigger.target();
+    let Ok((bstate, mut pressed, mut armed, mut key_state, repeat, disabled)) =
+        q_state.get_mut(button_id)
+    else {
+        return;
+    };
+    let event = trigger.event().input.clone();
+    if disabled || event.repeat || !matches!(event.key_code, KeyCode::Enter | KeyCode::Space) {
+        return;
+    }
+
+    trigger.propagate(false);
+    let click = ButtonClicked::new(
+        button_id,
+        InputSource::Keyboard,
+        Modifiers::from_keys(&keys),
+    );
+    match event.state {
+        // Auto-repeating buttons click on key down, and repeat until key up. Otherwise, Space
+        // holds the button down until the key is released.
+        ButtonState::Pressed if repeat.is_some() || event.key_code == KeyCode::Space => {
+            pressed.0 = true;
+            armed.0 = true;
+            key_state.key = Some(event.key_code);
+            if let Some(mut repeat) = repeat {
+                repeat.input = Some(InputSource::Keyboard);
+                repeat.timer.reset();
+                click_button(&mut commands, bstate, click);
             }
         }
+        ButtonState::Pressed => click_button(&mut commands, bstate, click),
+        ButtonState::Released if key_state.key == Some(event.key_code) => {
+            pressed.0 = false;
+            armed.0 = false;
+            key_state.key = None;
+            if repeat.is_none() {
+                click_button(&mut commands, bstate, click);
+            }
+        }
+        ButtonState::Released => {}
     }
 }
 
+#[allow(clippy::type_complexity)]
 pub(crate) fn button_on_pointer_click(
     mut trigger: Trigger<Pointer<Click>>,
-    mut q_state: Query<(&CoreButton, &mut ButtonPressed, Has<InteractionDisabled>)>,
+    mut q_state: Query<(
+        &CoreButton,
+        &mut ButtonPressed,
+        Has<ButtonAutoRepeat>,
+        Option<&ButtonDoubleClick>,
+        Option<&mut ButtonGestureState>,
+        Has<InteractionDisabled>,
+    )>,
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
     mut commands: Commands,
 ) {
-    if let Ok((bstate, pressed, disabled)) = q_state.get_mut(trigger.target()) {
+    let button_id = trigger.target();
+    if let Ok((bstate, pressed, auto_repeat, double_click, gestures, disabled)) =
+        q_state.get_mut(button_id)
+    {
         trigger.propagate(false);
-        if pressed.0 && !disabled {
-            if let Some(on_click) = bstate.on_click {
-                commands.run_system(on_click);
-            } else {
-                commands.trigger_targets(ButtonClicked, trigger.target());
+        // Auto-repeating buttons have already clicked when they were pressed.
+        if !pressed.0 || disabled || auto_repeat {
+            return;
+        }
+        let event = trigger.event();
+        let mut click = ButtonClicked::new(
+            button_id,
+            InputSource::Pointer {
+                pointer: event.pointer_id,
+                button: event.button,
+            },
+            Modifiers::from_keys(&keys),
+        );
+        let Some(mut gestures) = gestures else {
+            click_button(&mut commands, bstate, click);
+            return;
+        };
+        if gestures.long_pressed {
+            // The press was already reported as a long press.
+            return;
+        }
+
+        let Some(double_click) = double_click else {
+            click_button(&mut commands, bstate, click);
+            return;
+        };
+        let now = time.elapsed_secs();
+        let position = event.pointer_location.position;
+        match gestures.last_click {
+            Some((last_time, last_position))
+                if now - last_time <= double_click.interval
+                    && position.distance(last_position) <= double_click.distance =>
+            {
+                // A third click starts a new double click, rather than completing another.
+                gestures.last_click = None;
+                click.click_count = 2;
+                click_button(&mut commands, bstate, click);
+                if let Some(on_double_click) = bstate.on_double_click {
+                    commands.run_system(on_double_click);
+                } else {
+                    commands.trigger_targets(ButtonDoubleClicked, button_id);
+                }
+            }
+            _ => {
+                gestures.last_click = Some((now, position));
+                click_button(&mut commands, bstate, click);
             }
         }
     }
 }
 
+#[allow(clippy::type_complexity)]
 pub(crate) fn button_on_pointer_down(
     mut trigger: Trigger<Pointer<Pressed>>,
-    mut q_state: Query<(&mut ButtonPressed, Has<InteractionDisabled>)>,
+    mut q_state: Query<(
+        &CoreButton,
+        &mut ButtonPressed,
+        &mut ButtonArmed,
+        &mut ButtonKeyState,
+        Option<&mut ButtonRepeatState>,
+        Option<&mut ButtonGestureState>,
+        Has<TabIndex>,
+        Has<InteractionDisabled>,
+    )>,
     mut focus: ResMut<InputFocus>,
     mut focus_visible: ResMut<InputFocusVisible>,
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut commands: Commands,
 ) {
-    if let Ok((mut pressed, disabled)) = q_state.get_mut(trigger.target()) {
+    if let Ok((
+        bstate,
+        mut pressed,
+        mut armed,
+        mut key_state,
+        repeat,
+        gestures,
+        focusable,
+        disabled,
+    )) = q_state.get_mut(trigger.target())
+    {
         trigger.propagate(false);
         if !disabled {
             pressed.0 = true;
-            focus.0 = Some(trigger.target());
-            focus_visible.0 = false;
+            armed.0 = true;
+            if focusable {
+                focus.0 = Some(trigger.target());
+                focus_visible.0 = false;
+            }
+            // The pointer takes over from any key which was holding the button down.
+            key_state.key = None;
+            if let Some(mut gestures) = gestures {
+                gestures.press_start = Some(time.elapsed_secs());
+                gestures.long_pressed = false;
+            }
+            if let Some(mut repeat) = repeat {
+                let input = InputSource::Pointer {
+                    pointer: trigger.event().pointer_id,
+                    button: trigger.event().button,
+                };
+                repeat.input = Some(input);
+                repeat.timer.reset();
+                click_button(
+                    &mut commands,
+                    bstate,
+                    ButtonClicked::new(trigger.target(), input, Modifiers::from_keys(&keys)),
+                );
+            }
         }
     }
 }
 
 pub(crate) fn button_on_pointer_up(
     mut trigger: Trigger<Pointer<Released>>,
-    mut q_state: Query<(&mut ButtonPressed, Has<InteractionDisabled>)>,
+    mut q_state: Query<
+        (
+            &mut ButtonPressed,
+            &mut ButtonArmed,
+            Has<InteractionDisabled>,
+        ),
+        With<CoreButton>,
+    >,
 ) {
-    if let Ok((mut pressed, disabled)) = q_state.get_mut(trigger.target()) {
+    if let Ok((mut pressed, mut armed, disabled)) = q_state.get_mut(trigger.target()) {
         trigger.propagate(false);
         if !disabled {
             pressed.0 = false;
+            armed.0 = false;
         }
     }
 }
 
 pub(crate) fn button_on_pointer_drag_end(
     mut trigger: Trigger<Pointer<DragEnd>>,
-    mut q_state: Query<(&mut ButtonPressed, Has<InteractionDisabled>)>,
+    mut q_state: Query<
+        (
+            &mut ButtonPressed,
+            &mut ButtonArmed,
+            Has<InteractionDisabled>,
+        ),
+        With<CoreButton>,
+    >,
 ) {
-    if let Ok((mut pressed, disabled)) = q_state.get_mut(trigger.target()) {
+    if let Ok((mut pressed, mut armed, disabled)) = q_state.get_mut(trigger.target()) {
         trigger.propagate(false);
         if !disabled {
             pressed.0 = false;
+            armed.0 = false;
         }
     }
 }
 
 pub(crate) fn button_on_pointer_cancel(
     mut trigger: Trigger<Pointer<Cancel>>,
-    mut q_state: Query<(&mut ButtonPressed, Has<InteractionDisabled>)>,
+    mut q_state: Query<
+        (
+            &mut ButtonPressed,
+            &mut ButtonArmed,
+            Has<InteractionDisabled>,
+        ),
+        With<CoreButton>,
+    >,
 ) {
-    if let Ok((mut pressed, disabled)) = q_state.get_mut(trigger.target()) {
+    if let Ok((mut pressed, mut armed, disabled)) = q_state.get_mut(trigger.target()) {
         trigger.propagate(false);
         if !disabled {
             pressed.0 = false;
+            armed.0 = false;
+        }
+    }
+}
+
+/// Re-arms a pressed button when the pointer returns to it.
+pub(crate) fn button_on_pointer_over(
+    trigger: Trigger<Pointer<Over>>,
+    mut q_state: Query<(&ButtonPressed, &mut ButtonArmed, &ButtonKeyState), With<CoreButton>>,
+) {
+    if let Ok((pressed, mut armed, key_state)) = q_state.get_mut(trigger.target()) {
+        // Presses which are held by a key don't depend on where the pointer is.
+        if pressed.0 && key_state.key.is_none() && !armed.0 {
+            armed.0 = true;
+        }
+    }
+}
+
+/// Disarms a pressed button while the pointer is outside of it, since releasing the pointer
+/// there won't click the button.
+pub(crate) fn button_on_pointer_out(
+    trigger: Trigger<Pointer<Out>>,
+    mut q_state: Query<(&ButtonPressed, &mut ButtonArmed, &ButtonKeyState), With<CoreButton>>,
+) {
+    if let Ok((pressed, mut armed, key_state)) = q_state.get_mut(trigger.target()) {
+        if pressed.0 && key_state.key.is_none() && armed.0 {
+            armed.0 = false;
+        }
+    }
+}
+
+/// Releases buttons which are held down by a key, without clicking them, if the button loses
+/// focus or is disabled. The button won't see the key up event in that case.
+fn button_cancel_key_press(
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<InputFocus>,
+    mut q_state: Query<(
+        Entity,
+        &mut ButtonPressed,
+        &mut ButtonArmed,
+        &mut ButtonKeyState,
+        Has<InteractionDisabled>,
+    )>,
+) {
+    for (button_id, mut pressed, mut armed, mut key_state, disabled) in q_state.iter_mut() {
+        let Some(key) = key_state.key else {
+            continue;
+        };
+        if disabled || focus.0 != Some(button_id) || !keys.pressed(key) {
+            pressed.0 = false;
+            armed.0 = false;
+            key_state.key = None;
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn button_auto_repeat(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut q_state: Query<(
+        Entity,
+        &CoreButton,
+        &ButtonAutoRepeat,
+        &mut ButtonPressed,
+        &mut ButtonArmed,
+        &mut ButtonRepeatState,
+        Has<InteractionDisabled>,
+    )>,
+    mut commands: Commands,
+) {
+    for (button_id, bstate, auto_repeat, mut pressed, mut armed, mut repeat, disabled) in
+        q_state.iter_mut()
+    {
+        if !pressed.0 {
+            continue;
+        }
+        if disabled {
+            pressed.0 = false;
+            armed.0 = false;
+            continue;
+        }
+        if !armed.0 {
+            // Repeating pauses while the pointer is outside of the button.
+            continue;
+        }
+
+        let count = repeat
+            .timer
+            .tick(time.delta_secs(), auto_repeat.delay, auto_repeat.interval);
+        let Some(input) = repeat.input else {
+            continue;
+        };
+        for _ in 0..count {
+            click_button(
+                &mut commands,
+                bstate,
+                ButtonClicked::new(button_id, input, Modifiers::from_keys(&keys)),
+            );
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn button_long_press(
+    time: Res<Time>,
+    mut q_state: Query<(
+        Entity,
+        &CoreButton,
+        &ButtonLongPress,
+        &ButtonPressed,
+        &ButtonArmed,
+        &mut ButtonGestureState,
+        Has<InteractionDisabled>,
+    )>,
+    mut commands: Commands,
+) {
+    let now = time.elapsed_secs();
+    for (button_id, bstate, long_press, pressed, armed, mut gestures, disabled) in
+        q_state.iter_mut()
+    {
+        let Some(press_start) = gestures.press_start else {
+            continue;
+        };
+        // Moving the pointer off the button cancels the long press.
+        if !pressed.0 || !armed.0 || disabled {
+            gestures.press_start = None;
+            continue;
+        }
+        if now - press_start >= long_press.duration {
+            gestures.press_start = None;
+            gestures.long_pressed = true;
+            // A long press can't be the first half of a double click.
+            gestures.last_click = None;
+            if let Some(on_long_press) = bstate.on_long_press {
+                commands.run_system(on_long_press);
+            } else {
+                commands.trigger_targets(ButtonLongPressed, button_id);
+            }
+        }
+    }
+}
+
+pub(crate) fn button_on_action_request(
+    mut requests: EventReader<ActionRequest>,
+    q_state: Query<(&CoreButton, Has<InteractionDisabled>)>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+) {
+    for request in requests.read() {
+        if request.action != Action::Click {
+            continue;
+        }
+        let Some(button_id) = action_target(request) else {
+            continue;
+        };
+        if let Ok((bstate, false)) = q_state.get(button_id) {
+            let click = ButtonClicked::new(
+                button_id,
+                InputSource::Accessibility,
+                Modifiers::from_keys(&keys),
+            );
+            click_button(&mut commands, bstate, click);
         }
     }
 }
@@ -119,6 +555,16 @@ impl Plugin for CoreButtonPlugin {
             .add_observer(button_on_pointer_up)
             .add_observer(button_on_pointer_click)
             .add_observer(button_on_pointer_drag_end)
-            .add_observer(button_on_pointer_cancel);
+            .add_observer(button_on_pointer_cancel)
+            .add_observer(button_on_pointer_over)
+            .add_observer(button_on_pointer_out)
+            .add_systems(
+                Update,
+                (
+                    button_on_action_request,
+                    (button_cancel_key_press, button_auto_repeat).chain(),
+                    button_long_press,
+                ),
+            );
     }
 }
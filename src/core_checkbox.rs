@@ -1,27 +1,36 @@
-use accesskit::Role;
+use accesskit::{Action, Role};
 use bevy::{
-    a11y::AccessibilityNode,
+    a11y::{AccessibilityNode, ActionRequest},
     ecs::system::SystemId,
     input::{keyboard::KeyboardInput, ButtonState},
     input_focus::{FocusedInput, InputFocus, InputFocusVisible},
     prelude::*,
 };
 
-use crate::{interaction_states::Checked, InteractionDisabled, ValueChange};
+use crate::{
+    accessibility::{accessible_node, action_target},
+    interaction_states::Checked,
+    InputSource, InteractionDisabled, Modifiers, ValueChange,
+};
 
 /// Headless widget implementation for checkboxes. The `checked` represents the current state
 /// of the checkbox. The `on_change` field is a system that will be run when the checkbox
 /// is clicked, or when the Enter or Space key is pressed while the checkbox is focused.
 /// If the `on_change` field is `None`, the checkbox will emit a `ValueChange` event instead.
+/// Assistive technologies can toggle the checkbox with the `Click` action.
 #[derive(Component, Debug)]
-#[require(AccessibilityNode(accesskit::Node::new(Role::CheckBox)), Checked)]
+#[require(
+    AccessibilityNode(accessible_node(Role::CheckBox, &[Action::Click, Action::Focus])),
+    Checked
+)]
 pub struct CoreCheckbox {
-    pub on_change: Option<SystemId<In<bool>>>,
+    pub on_change: Option<SystemId<In<ValueChange<bool>>>>,
 }
 
 fn checkbox_on_key_input(
     mut trigger: Trigger<FocusedInput<KeyboardInput>>,
     q_state: Query<(&CoreCheckbox, &Checked, Has<InteractionDisabled>)>,
+    keys: Res<ButtonInput<KeyCode>>,
     mut commands: Commands,
 ) {
     if let Ok((checkbox, checked, disabled)) = q_state.get(trigger.target()) {
@@ -33,11 +42,14 @@ fn checkbox_on_key_input(
         {
             let is_checked = checked.0;
             trigger.propagate(false);
-            if let Some(on_change) = checkbox.on_change {
-                commands.run_system_with(on_change, !is_checked);
-            } else {
-                commands.trigger_targets(ValueChange(!is_checked), trigger.target());
+            ValueChange {
+                value: !is_checked,
+                old_value: Some(is_checked),
+                source: trigger.target(),
+                input: InputSource::Keyboard,
+                modifiers: Modifiers::from_keys(&keys),
             }
+            .emit(&mut commands, checkbox.on_change);
         }
     }
 }
@@ -47,6 +59,7 @@ fn checkbox_on_pointer_click(
     q_state: Query<(&CoreCheckbox, &Checked, Has<InteractionDisabled>)>,
     mut focus: ResMut<InputFocus>,
     mut focus_visible: ResMut<InputFocusVisible>,
+    keys: Res<ButtonInput<KeyCode>>,
     mut commands: Commands,
 ) {
     if let Ok((checkbox, checked, disabled)) = q_state.get(trigger.target()) {
@@ -56,11 +69,44 @@ fn checkbox_on_pointer_click(
         trigger.propagate(false);
         if !disabled {
             let is_checked = checked.0;
-            if let Some(on_change) = checkbox.on_change {
-                commands.run_system_with(on_change, !is_checked);
-            } else {
-                commands.trigger_targets(ValueChange(!is_checked), trigger.target());
+            ValueChange {
+                value: !is_checked,
+                old_value: Some(is_checked),
+                source: checkbox_id,
+                input: InputSource::Pointer {
+                    pointer: trigger.event().pointer_id,
+                    button: trigger.event().button,
+                },
+                modifiers: Modifiers::from_keys(&keys),
+            }
+            .emit(&mut commands, checkbox.on_change);
+        }
+    }
+}
+
+fn checkbox_on_action_request(
+    mut requests: EventReader<ActionRequest>,
+    q_state: Query<(&CoreCheckbox, &Checked, Has<InteractionDisabled>)>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+) {
+    for request in requests.read() {
+        if request.action != Action::Click {
+            continue;
+        }
+        let Some(checkbox_id) = action_target(request) else {
+            continue;
+        };
+        if let Ok((checkbox, checked, false)) = q_state.get(checkbox_id) {
+            let is_checked = checked.0;
+            ValueChange {
+                value: !is_checked,
+                old_value: Some(is_checked),
+                source: checkbox_id,
+                input: InputSource::Accessibility,
+                modifiers: Modifiers::from_keys(&keys),
             }
+            .emit(&mut commands, checkbox.on_change);
         }
     }
 }
@@ -70,6 +116,7 @@ pub struct CoreCheckboxPlugin;
 impl Plugin for CoreCheckboxPlugin {
     fn build(&self, app: &mut App) {
         app.add_observer(checkbox_on_key_input)
-            .add_observer(checkbox_on_pointer_click);
+            .add_observer(checkbox_on_pointer_click)
+            .add_systems(Update, checkbox_on_action_request);
     }
 }
@@ -0,0 +1,206 @@
+use accesskit::{Action, NodeId, Role};
+use bevy::{
+    a11y::{AccessibilityNode, ActionRequest},
+    ecs::system::SystemId,
+    input::{keyboard::KeyboardInput, ButtonState},
+    input_focus::{FocusedInput, InputFocus, InputFocusVisible},
+    prelude::*,
+    ui::UiSystem,
+};
+
+use crate::{
+    accessibility::{accessible_node, action_target},
+    interaction_states::Expanded,
+    InputSource, InteractionDisabled, Modifiers, ValueChange,
+};
+
+/// Headless widget implementation for disclosure toggles, which expand or collapse a section of
+/// content. The [`Expanded`] component represents the current state of the toggle. The
+/// `on_change` field is a system that will be run when the toggle is clicked, or when the Enter
+/// or Space key is pressed while the toggle is focused. If the `on_change` field is `None`, the
+/// toggle will emit a `ValueChange` event instead. Assistive technologies can toggle it with the
+/// `Click` action.
+///
+/// If the `content` field is set, the referenced entity will be hidden (by setting its
+/// `display` to `Display::None`) while the toggle is collapsed, and restored when it is
+/// expanded. If `content` is changed to a different entity, the previous content is restored.
+/// The content entity is also reported to assistive technologies as being controlled by the
+/// toggle.
+#[derive(Component, Debug)]
+#[require(
+    AccessibilityNode(accessible_node(Role::Button, &[Action::Click, Action::Focus])),
+    Expanded
+)]
+pub struct CoreDisclosureToggle {
+    pub on_change: Option<SystemId<In<ValueChange<bool>>>>,
+    /// Entity containing the content which is shown or hidden by this toggle.
+    pub content: Option<Entity>,
+}
+
+/// Component which stores the original `display` of a disclosure toggle's content while the
+/// content is collapsed.
+#[derive(Component, Debug)]
+struct CollapsedDisplay(Display);
+
+/// Component which records the content entity that a disclosure toggle is currently showing and
+/// hiding, so that the content can be restored if the toggle's `content` field changes.
+#[derive(Component, Debug)]
+struct ManagedContent(Option<Entity>);
+
+fn disclosure_on_key_input(
+    mut trigger: Trigger<FocusedInput<KeyboardInput>>,
+    q_state: Query<(&CoreDisclosureToggle, &Expanded, Has<InteractionDisabled>)>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+) {
+    if let Ok((toggle, expanded, disabled)) = q_state.get(trigger.target()) {
+        let event = &trigger.event().input;
+        if !disabled
+            && event.state == ButtonState::Pressed
+            && !event.repeat
+            && (event.key_code == KeyCode::Enter || event.key_code == KeyCode::Space)
+        {
+            let is_expanded = expanded.0;
+            trigger.propagate(false);
+            ValueChange {
+                value: !is_expanded,
+                old_value: Some(is_expanded),
+                source: trigger.target(),
+                input: InputSource::Keyboard,
+                modifiers: Modifiers::from_keys(&keys),
+            }
+            .emit(&mut commands, toggle.on_change);
+        }
+    }
+}
+
+fn disclosure_on_pointer_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    q_state: Query<(&CoreDisclosureToggle, &Expanded, Has<InteractionDisabled>)>,
+    mut focus: ResMut<InputFocus>,
+    mut focus_visible: ResMut<InputFocusVisible>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+) {
+    if let Ok((toggle, expanded, disabled)) = q_state.get(trigger.target()) {
+        let toggle_id = trigger.target();
+        focus.0 = Some(toggle_id);
+        focus_visible.0 = false;
+        trigger.propagate(false);
+        if !disabled {
+            let is_expanded = expanded.0;
+            ValueChange {
+                value: !is_expanded,
+                old_value: Some(is_expanded),
+                source: toggle_id,
+                input: InputSource::Pointer {
+                    pointer: trigger.event().pointer_id,
+                    button: trigger.event().button,
+                },
+                modifiers: Modifiers::from_keys(&keys),
+            }
+            .emit(&mut commands, toggle.on_change);
+        }
+    }
+}
+
+fn disclosure_on_action_request(
+    mut requests: EventReader<ActionRequest>,
+    q_state: Query<(&CoreDisclosureToggle, &Expanded, Has<InteractionDisabled>)>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+) {
+    for request in requests.read() {
+        if request.action != Action::Click {
+            continue;
+        }
+        let Some(toggle_id) = action_target(request) else {
+            continue;
+        };
+        if let Ok((toggle, expanded, false)) = q_state.get(toggle_id) {
+            let is_expanded = expanded.0;
+            ValueChange {
+                value: !is_expanded,
+                old_value: Some(is_expanded),
+                source: toggle_id,
+                input: InputSource::Accessibility,
+                modifiers: Modifiers::from_keys(&keys),
+            }
+            .emit(&mut commands, toggle.on_change);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_disclosure_content(
+    mut q_toggle: Query<
+        (
+            Entity,
+            &CoreDisclosureToggle,
+            &Expanded,
+            Option<&ManagedContent>,
+            &mut AccessibilityNode,
+        ),
+        Or<(Changed<CoreDisclosureToggle>, Changed<Expanded>)>,
+    >,
+    mut q_content: Query<(&mut Node, Option<&CollapsedDisplay>)>,
+    mut commands: Commands,
+) {
+    for (toggle_id, toggle, expanded, managed, mut a11y) in q_toggle.iter_mut() {
+        // The accessibility node may have been added after the `Expanded` state.
+        a11y.set_expanded(expanded.0);
+
+        // Show the previous content again if the toggle now controls a different entity.
+        let previous = managed.and_then(|managed| managed.0);
+        if previous != toggle.content {
+            if let Some(previous) = previous {
+                if let Ok((mut node, Some(CollapsedDisplay(display)))) = q_content.get_mut(previous)
+                {
+                    node.display = *display;
+                    commands.entity(previous).remove::<CollapsedDisplay>();
+                }
+            }
+            commands
+                .entity(toggle_id)
+                .insert(ManagedContent(toggle.content));
+        }
+
+        let Some(content_id) = toggle.content else {
+            a11y.clear_controls();
+            continue;
+        };
+        a11y.set_controls(vec![NodeId(content_id.to_bits())]);
+
+        let Ok((mut node, collapsed)) = q_content.get_mut(content_id) else {
+            continue;
+        };
+        match (expanded.0, collapsed) {
+            (true, Some(CollapsedDisplay(display))) => {
+                // Restore the display mode that the content had before it was collapsed.
+                node.display = *display;
+                commands.entity(content_id).remove::<CollapsedDisplay>();
+            }
+            (false, None) if node.display != Display::None => {
+                commands
+                    .entity(content_id)
+                    .insert(CollapsedDisplay(node.display));
+                node.display = Display::None;
+            }
+            _ => {}
+        }
+    }
+}
+
+pub struct CoreDisclosureTogglePlugin;
+
+impl Plugin for CoreDisclosureTogglePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(disclosure_on_key_input)
+            .add_observer(disclosure_on_pointer_click)
+            .add_systems(Update, disclosure_on_action_request)
+            .add_systems(
+                PostUpdate,
+                update_disclosure_content.before(UiSystem::Layout),
+            );
+    }
+}
@@ -1,25 +1,33 @@
-use accesskit::Role;
+use accesskit::{Action, Role};
 use bevy::{
-    a11y::AccessibilityNode,
+    a11y::{AccessibilityNode, ActionRequest},
     input_focus::{InputFocus, InputFocusVisible},
     prelude::*,
 };
 
-use crate::{interaction_states::Checked, ButtonClicked, InteractionDisabled};
+use crate::{
+    accessibility::{accessible_node, action_target},
+    interaction_states::Checked,
+    ButtonClicked, InputSource, InteractionDisabled, Modifiers,
+};
 
 /// Headless widget implementation for radio buttons. Note that this does not handle the mutual
 /// exclusion of radio buttons in the same group; that should be handled by the parent component.
 /// (This is relatively easy if the parent is a reactive widget.)
 ///
-/// The widget emits a `ButtonClick` event when clicked, or when the `Enter` or `Space` key is
+/// The widget emits a [`ButtonClicked`] event when clicked, or when the `Enter` or `Space` key is
 /// pressed while the radio button is focused. This event is normally handled by the parent
-/// `CoreRadioGroup` component.
+/// `CoreRadioGroup` component. Assistive technologies can select the radio button with the
+/// `Click` action.
 ///
 /// According to the WAI-ARIA best practices document, radio buttons should not be focusable,
 /// but rather the enclosing group should be focusable.
 /// See https://www.w3.org/WAI/ARIA/apg/patterns/radio/
 #[derive(Component, Debug)]
-#[require(AccessibilityNode(accesskit::Node::new(Role::RadioButton)), Checked)]
+#[require(
+    AccessibilityNode(accessible_node(Role::RadioButton, &[Action::Click])),
+    Checked
+)]
 pub struct CoreRadio;
 
 fn radio_on_pointer_click(
@@ -27,6 +35,7 @@ fn radio_on_pointer_click(
     q_state: Query<(&Checked, Has<InteractionDisabled>), With<CoreRadio>>,
     mut focus: ResMut<InputFocus>,
     mut focus_visible: ResMut<InputFocusVisible>,
+    keys: Res<ButtonInput<KeyCode>>,
     mut commands: Commands,
 ) {
     if let Ok((checked, disabled)) = q_state.get(trigger.target()) {
@@ -38,7 +47,38 @@ fn radio_on_pointer_click(
             // If the radio is already checked, or disabled, we do nothing.
             return;
         }
-        commands.trigger_targets(ButtonClicked, trigger.target());
+        let input = InputSource::Pointer {
+            pointer: trigger.event().pointer_id,
+            button: trigger.event().button,
+        };
+        commands.trigger_targets(
+            ButtonClicked::new(checkbox_id, input, Modifiers::from_keys(&keys)),
+            checkbox_id,
+        );
+    }
+}
+
+fn radio_on_action_request(
+    mut requests: EventReader<ActionRequest>,
+    q_state: Query<(&Checked, Has<InteractionDisabled>), With<CoreRadio>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+) {
+    for request in requests.read() {
+        if request.action != Action::Click {
+            continue;
+        }
+        let Some(radio_id) = action_target(request) else {
+            continue;
+        };
+        // As with pointer clicks, checked or disabled radio buttons do nothing.
+        if let Ok((Checked(false), false)) = q_state.get(radio_id) {
+            let modifiers = Modifiers::from_keys(&keys);
+            commands.trigger_targets(
+                ButtonClicked::new(radio_id, InputSource::Accessibility, modifiers),
+                radio_id,
+            );
+        }
     }
 }
 
@@ -46,6 +86,7 @@ pub struct CoreRadioPlugin;
 
 impl Plugin for CoreRadioPlugin {
     fn build(&self, app: &mut App) {
-        app.add_observer(radio_on_pointer_click);
+        app.add_observer(radio_on_pointer_click)
+            .add_systems(Update, radio_on_action_request);
     }
 }
@@ -7,7 +7,9 @@ use bevy::{
     prelude::*,
 };
 
-use crate::{ButtonClicked, Checked, CoreRadio, InteractionDisabled, ValueChange};
+use crate::{
+    ButtonClicked, Checked, CoreRadio, InputSource, InteractionDisabled, Modifiers, ValueChange,
+};
 
 /// Headless widget implementation for a "radio group". This component is used to group multiple
 /// `CoreRadio` components together, allowing them to behave as a single unit. It implements
@@ -15,19 +17,20 @@ use crate::{ButtonClicked, Checked, CoreRadio, InteractionDisabled, ValueChange}
 ///
 /// The `CoreRadioGroup` component does not have any state itself, and makes no assumptions about
 /// what, if any, value is associated with each radio button. Instead, it relies on the `CoreRadio`
-/// components to trigger a `ButtonPress` event, and tranforms this into a `ValueChange` event
-/// which contains the id of the selected button. The app can then derive the selected value
-/// from this using app-specific data.
+/// components to trigger a `ButtonClicked` event, and tranforms this into a `ValueChange` event
+/// which contains the id of the selected button, along with the previously checked button. The
+/// app can then derive the selected value from this using app-specific data.
 #[derive(Component, Debug)]
 #[require(AccessibilityNode(accesskit::Node::new(Role::RadioGroup)))]
 pub struct CoreRadioGroup {
-    pub on_change: Option<SystemId<In<Entity>>>,
+    pub on_change: Option<SystemId<In<ValueChange<Entity>>>>,
 }
 
 fn radio_group_on_key_input(
     mut trigger: Trigger<FocusedInput<KeyboardInput>>,
     q_group: Query<(&CoreRadioGroup, &Children)>,
     q_radio: Query<(&Checked, Has<InteractionDisabled>), With<CoreRadio>>,
+    keys: Res<ButtonInput<KeyCode>>,
     mut commands: Commands,
 ) {
     if let Ok((CoreRadioGroup { on_change }, group_children)) = q_group.get(trigger.target()) {
@@ -104,11 +107,14 @@ fn radio_group_on_key_input(
             let (next_id, _) = radio_children[next_index];
 
             // Trigger the on_change event for the newly checked radio button
-            if let Some(on_change) = on_change {
-                commands.run_system_with(*on_change, next_id);
-            } else {
-                commands.trigger_targets(ValueChange(next_id), trigger.target());
+            ValueChange {
+                value: next_id,
+                old_value: radio_children.get(current_index).map(|(id, _)| *id),
+                source: trigger.target(),
+                input: InputSource::Keyboard,
+                modifiers: Modifiers::from_keys(&keys),
             }
+            .emit(&mut commands, *on_change);
         }
     }
 }
@@ -165,12 +171,17 @@ fn radio_group_on_button_click(
         return;
     }
 
-    // Trigger the on_change event for the newly checked radio button
-    if let Some(on_change) = on_change {
-        commands.run_system_with(*on_change, radio_id);
-    } else {
-        commands.trigger_targets(ValueChange(radio_id), group_id);
+    // Trigger the on_change event for the newly checked radio button, passing along the input
+    // which clicked it.
+    let click = trigger.event();
+    ValueChange {
+        value: radio_id,
+        old_value: current_radio,
+        source: group_id,
+        input: click.input,
+        modifiers: click.modifiers,
     }
+    .emit(&mut commands, *on_change);
 }
 
 pub struct CoreRadioGroupPlugin;
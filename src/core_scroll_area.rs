@@ -0,0 +1,605 @@
+use bevy::{
+    input::{
+        keyboard::KeyboardInput,
+        mouse::{MouseScrollUnit, MouseWheel},
+        ButtonState,
+    },
+    input_focus::{tab_navigation::TabIndex, FocusedInput, InputFocus, InputFocusVisible},
+    picking::{hover::HoverMap, pointer::PointerId},
+    prelude::*,
+    ui::UiSystem,
+};
+
+use crate::{
+    scroll_into_view::{scroll_focus_into_view, FocusScrollSettings},
+    scroll_link::ScrollLinkPlugin,
+};
+
+/// Headless scroll area, which scrolls its [`ScrollPosition`] in response to the mouse wheel and
+/// trackpad gestures while the pointer is over the area or any of its descendants. This is
+/// opt-in: scrolling containers without this component are only moved by their scrollbars.
+///
+/// Wheel events measured in lines are multiplied by `line_size`; events measured in pixels are
+/// used as-is. Holding Shift converts vertical wheel movement into horizontal scrolling. The
+/// scroll position is clamped so that the content never scrolls past its edges; any movement
+/// which is left over once an inner scroll area reaches its edge is passed on to the nearest
+/// enclosing scroll area.
+///
+/// Dragging the area with a touch pointer scrolls the content directly. Add the
+/// [`SmoothScroll`] component to animate scrolling.
+///
+/// Adding a [`TabIndex`] makes the scroll area focusable, and pressing on it gives it focus.
+/// While the scroll area or any of its descendants is focused, keys which aren't handled by the
+/// focused widget scroll the area: the arrow keys scroll by `line_size`, PageUp and PageDown (or
+/// Shift+Space and Space) scroll by a page, and Home and End scroll to the top and bottom of the
+/// content. Keys which would scroll past the edge are passed on to the enclosing scroll area.
+///
+/// The plugin for this component also scrolls any scrolling container, with or without this
+/// component, to reveal entities which receive keyboard focus; see [`FocusScrollSettings`].
+#[derive(Component, Debug)]
+#[require(ScrollPosition)]
+pub struct CoreScrollArea {
+    /// Distance to scroll for each line of wheel movement or arrow key press, in logical pixels.
+    pub line_size: f32,
+}
+
+impl Default for CoreScrollArea {
+    fn default() -> Self {
+        Self { line_size: 20.0 }
+    }
+}
+
+/// Distance from the destination, in logical pixels, at which an easing animation stops.
+const SETTLE_DISTANCE: f32 = 0.5;
+
+/// Time without any gesture movement, in seconds, after which a trackpad gesture is considered
+/// to have ended. Trackpad events often arrive less frequently than frames are drawn, so a frame
+/// without movement doesn't mean that the gesture is over.
+const GESTURE_IDLE_TIME: f32 = 0.075;
+
+/// Enables animated scrolling for a scroll area. When this component is present, wheel steps
+/// and scrollbar page clicks ease toward their destination instead of moving there immediately.
+/// Trackpad and touch gestures still move the content directly, but once the gesture ends the
+/// content keeps moving at the speed of the gesture, slowing down due to friction until it stops
+/// or reaches the edge of the content. A touch gesture ends when the finger is lifted, and a
+/// trackpad gesture ends once no movement has been reported for a short time. Platforms which
+/// generate their own momentum events, such as macOS, keep reporting movement until the content
+/// has nearly stopped, so the fling which follows is negligible.
+///
+/// Animations are driven by the [`Time`] resource, so they are deterministic for a given
+/// sequence of frame times. Dragging a scrollbar thumb moves the content immediately, and stops
+/// any animation in progress.
+#[derive(Component, Debug, Clone)]
+#[require(ScrollAnimation)]
+pub struct SmoothScroll {
+    /// Time constant of the easing animation, in seconds. After this much time, about two thirds
+    /// of the remaining distance has been covered. A value of zero disables easing.
+    pub ease_time: f32,
+    /// Rate at which flings slow down. The velocity is multiplied by `exp(-friction * t)`, where
+    /// `t` is the elapsed time in seconds.
+    pub friction: f32,
+    /// Speed below which a fling stops, in logical pixels per second.
+    pub min_fling_speed: f32,
+}
+
+impl Default for SmoothScroll {
+    fn default() -> Self {
+        Self {
+            ease_time: 0.08,
+            friction: 4.0,
+            min_fling_speed: 20.0,
+        }
+    }
+}
+
+/// Component used to manage the state of an animated scroll area.
+#[derive(Component, Debug, Default)]
+pub struct ScrollAnimation {
+    /// Offset which the scroll area is easing toward, if any.
+    target: Option<Vec2>,
+    /// Velocity of the content, in logical pixels per second. While a gesture is in progress
+    /// this is an estimate of the gesture speed; afterwards it is the speed of the fling.
+    velocity: Vec2,
+    /// Distance scrolled directly by a trackpad or touch gesture since the last update.
+    gesture_delta: Option<Vec2>,
+    /// Elapsed time at which the gesture in progress last moved the content, if any.
+    last_gesture_time: Option<f32>,
+    /// Whether a touch pointer is currently holding the content.
+    held: bool,
+    /// Whether the touch pointer which held the content has been lifted, which ends the gesture
+    /// without waiting for it to go idle.
+    released: bool,
+}
+
+impl ScrollAnimation {
+    /// Returns true if the scroll area is easing toward a destination, or coasting after a
+    /// fling.
+    pub fn is_animating(&self) -> bool {
+        self.target.is_some() || (self.last_gesture_time.is_none() && self.velocity != Vec2::ZERO)
+    }
+
+    /// Returns the offset at which the scroll area will come to rest once the current easing
+    /// animation finishes.
+    pub(crate) fn destination(&self, scroll_pos: &ScrollPosition) -> Vec2 {
+        self.target
+            .unwrap_or(Vec2::new(scroll_pos.offset_x, scroll_pos.offset_y))
+    }
+
+    /// Start easing toward `target`, cancelling any fling.
+    pub(crate) fn ease_to(&mut self, target: Vec2) {
+        self.target = Some(target);
+        self.velocity = Vec2::ZERO;
+        self.last_gesture_time = None;
+        self.released = false;
+    }
+
+    /// Stop all animation.
+    pub(crate) fn stop(&mut self) {
+        self.target = None;
+        self.velocity = Vec2::ZERO;
+        self.gesture_delta = None;
+        self.last_gesture_time = None;
+        self.released = false;
+    }
+
+    /// Record movement which was applied directly by a trackpad or touch gesture, so that the
+    /// content can keep moving once the gesture ends.
+    fn track_gesture(&mut self, delta: Vec2) {
+        self.target = None;
+        *self.gesture_delta.get_or_insert(Vec2::ZERO) += delta;
+    }
+}
+
+/// Returns the maximum scroll offset of a scrolling node along each axis, in logical pixels.
+pub(crate) fn max_scroll_offset(node: &ComputedNode) -> Vec2 {
+    ((node.content_size() - node.size()) * node.inverse_scale_factor).max(Vec2::ZERO)
+}
+
+fn scroll_area_on_mouse_wheel(
+    mut wheel_events: EventReader<MouseWheel>,
+    hover_map: Option<Res<HoverMap>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    q_parent: Query<&ChildOf>,
+    mut q_scroll_area: Query<(
+        &CoreScrollArea,
+        &mut ScrollPosition,
+        &ComputedNode,
+        Option<&mut ScrollAnimation>,
+    )>,
+) {
+    let Some(hover_map) = hover_map else {
+        wheel_events.clear();
+        return;
+    };
+    let Some(hover_set) = hover_map.get(&PointerId::Mouse) else {
+        wheel_events.clear();
+        return;
+    };
+    // Only the topmost entity under the pointer is scrolled, so that overlapping hits don't
+    // scroll a shared ancestor more than once.
+    let Some(hovered) = hover_set
+        .iter()
+        .min_by(|(_, a), (_, b)| a.depth.total_cmp(&b.depth))
+        .map(|(entity, _)| *entity)
+    else {
+        wheel_events.clear();
+        return;
+    };
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    for event in wheel_events.read() {
+        // Scroll areas which contain the pointer, innermost first.
+        let mut remaining: Option<Vec2> = None;
+        for area_id in std::iter::once(hovered).chain(q_parent.iter_ancestors(hovered)) {
+            let Ok((area, mut scroll_pos, node, anim)) = q_scroll_area.get_mut(area_id) else {
+                continue;
+            };
+            // Line sizes are taken from the innermost scroll area.
+            let delta = remaining.get_or_insert_with(|| {
+                let mut delta = match event.unit {
+                    MouseScrollUnit::Line => Vec2::new(event.x, event.y) * area.line_size,
+                    MouseScrollUnit::Pixel => Vec2::new(event.x, event.y),
+                };
+                if shift && delta.x == 0. {
+                    delta = Vec2::new(delta.y, delta.x);
+                }
+                // Positive wheel movement scrolls towards the start of the content.
+                -delta
+            });
+
+            // Wheel steps are animated, but trackpad gestures are already smooth.
+            let max_offset = max_scroll_offset(node);
+            let offset = match (&anim, event.unit) {
+                (Some(anim), MouseScrollUnit::Line) => anim.destination(&scroll_pos),
+                _ => Vec2::new(scroll_pos.offset_x, scroll_pos.offset_y),
+            };
+            let new_offset = (offset + *delta).clamp(Vec2::ZERO, max_offset);
+            if new_offset != offset {
+                match (anim, event.unit) {
+                    (Some(mut anim), MouseScrollUnit::Line) => anim.ease_to(new_offset),
+                    (anim, _) => {
+                        scroll_pos.offset_x = new_offset.x;
+                        scroll_pos.offset_y = new_offset.y;
+                        if let Some(mut anim) = anim {
+                            anim.track_gesture(new_offset - offset);
+                        }
+                    }
+                }
+            }
+            *delta -= new_offset - offset;
+            if *delta == Vec2::ZERO {
+                break;
+            }
+        }
+    }
+}
+
+fn scroll_area_on_pointer_down(
+    trigger: Trigger<Pointer<Pressed>>,
+    mut q_scroll_area: Query<(Option<&mut ScrollAnimation>, Has<TabIndex>), With<CoreScrollArea>>,
+    q_parent: Query<&ChildOf>,
+    mut focus: ResMut<InputFocus>,
+    mut focus_visible: ResMut<InputFocusVisible>,
+) {
+    let area_id = trigger.target();
+    if let Ok((anim, focusable)) = q_scroll_area.get_mut(area_id) {
+        // Pressing on the content stops a fling in progress.
+        if let Some(mut anim) = anim {
+            anim.stop();
+        }
+        // Set focus to the scroll area and hide the focus ring, unless a widget between the
+        // pressed entity and the scroll area has already taken focus.
+        let pressed_id = trigger.event().target;
+        let claimed = focus.0.is_some_and(|focus_id| {
+            focus_id != area_id
+                && (focus_id == pressed_id
+                    || q_parent
+                        .iter_ancestors(pressed_id)
+                        .take_while(|ancestor| *ancestor != area_id)
+                        .any(|ancestor| ancestor == focus_id))
+        });
+        if focusable && !claimed {
+            focus.0 = Some(area_id);
+            focus_visible.0 = false;
+        }
+    }
+}
+
+fn scroll_area_on_key_input(
+    mut trigger: Trigger<FocusedInput<KeyboardInput>>,
+    mut q_scroll_area: Query<(
+        &CoreScrollArea,
+        &mut ScrollPosition,
+        &ComputedNode,
+        Option<&mut ScrollAnimation>,
+    )>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    let Ok((area, mut scroll_pos, node, anim)) = q_scroll_area.get_mut(trigger.target()) else {
+        return;
+    };
+    let event = &trigger.event().input;
+    if event.state != ButtonState::Pressed {
+        return;
+    }
+
+    let max_offset = max_scroll_offset(node);
+    let page = node.size().y * node.inverse_scale_factor;
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let offset = match &anim {
+        Some(anim) => anim.destination(&scroll_pos),
+        None => Vec2::new(scroll_pos.offset_x, scroll_pos.offset_y),
+    };
+    let new_offset = match event.key_code {
+        KeyCode::ArrowUp => offset - Vec2::Y * area.line_size,
+        KeyCode::ArrowDown => offset + Vec2::Y * area.line_size,
+        KeyCode::ArrowLeft => offset - Vec2::X * area.line_size,
+        KeyCode::ArrowRight => offset + Vec2::X * area.line_size,
+        KeyCode::PageUp => offset - Vec2::Y * page,
+        KeyCode::PageDown => offset + Vec2::Y * page,
+        KeyCode::Space if shift => offset - Vec2::Y * page,
+        KeyCode::Space => offset + Vec2::Y * page,
+        KeyCode::Home => Vec2::new(offset.x, 0.),
+        KeyCode::End => Vec2::new(offset.x, max_offset.y),
+        _ => {
+            return;
+        }
+    }
+    .clamp(Vec2::ZERO, max_offset);
+
+    // If the area is already at the edge, let the enclosing scroll area handle the key.
+    if new_offset == offset {
+        return;
+    }
+    trigger.propagate(false);
+    match anim {
+        Some(mut anim) => anim.ease_to(new_offset),
+        None => {
+            scroll_pos.offset_x = new_offset.x;
+            scroll_pos.offset_y = new_offset.y;
+        }
+    }
+}
+
+fn scroll_area_on_touch_drag_start(
+    trigger: Trigger<Pointer<DragStart>>,
+    mut q_anim: Query<&mut ScrollAnimation, With<CoreScrollArea>>,
+) {
+    if let PointerId::Touch(_) = trigger.event().pointer_id {
+        if let Ok(mut anim) = q_anim.get_mut(trigger.target()) {
+            anim.held = true;
+        }
+    }
+}
+
+fn scroll_area_on_touch_drag(
+    mut trigger: Trigger<Pointer<Drag>>,
+    mut q_scroll_area: Query<
+        (
+            &mut ScrollPosition,
+            &ComputedNode,
+            Option<&mut ScrollAnimation>,
+        ),
+        With<CoreScrollArea>,
+    >,
+) {
+    if !matches!(trigger.event().pointer_id, PointerId::Touch(_)) {
+        return;
+    }
+    if let Ok((mut scroll_pos, node, anim)) = q_scroll_area.get_mut(trigger.target()) {
+        trigger.propagate(false);
+        // The content follows the finger, so it moves opposite to the scroll offset.
+        let offset = Vec2::new(scroll_pos.offset_x, scroll_pos.offset_y);
+        let new_offset =
+            (offset - trigger.event().delta).clamp(Vec2::ZERO, max_scroll_offset(node));
+        scroll_pos.offset_x = new_offset.x;
+        scroll_pos.offset_y = new_offset.y;
+        if let Some(mut anim) = anim {
+            anim.track_gesture(new_offset - offset);
+        }
+    }
+}
+
+fn scroll_area_on_touch_drag_end(
+    trigger: Trigger<Pointer<DragEnd>>,
+    mut q_anim: Query<&mut ScrollAnimation, With<CoreScrollArea>>,
+) {
+    if let PointerId::Touch(_) = trigger.event().pointer_id {
+        if let Ok(mut anim) = q_anim.get_mut(trigger.target()) {
+            anim.held = false;
+            anim.released = true;
+        }
+    }
+}
+
+fn animate_scroll_areas(
+    time: Res<Time>,
+    mut q_scroll_area: Query<(
+        &SmoothScroll,
+        &mut ScrollAnimation,
+        &mut ScrollPosition,
+        &ComputedNode,
+    )>,
+) {
+    let delta_secs = time.delta_secs();
+    if delta_secs <= 0. {
+        return;
+    }
+    let now = time.elapsed_secs();
+
+    for (smooth, mut anim, mut scroll_pos, node) in q_scroll_area.iter_mut() {
+        let max_offset = max_scroll_offset(node);
+        let offset = Vec2::new(scroll_pos.offset_x, scroll_pos.offset_y);
+        let decay = (-smooth.friction * delta_secs).exp();
+
+        if let Some(gesture_delta) = anim.gesture_delta.take() {
+            // A gesture moved the content; estimate its speed from the time since it last did,
+            // which may span several frames.
+            anim.velocity = match anim.last_gesture_time {
+                Some(last) => anim
+                    .velocity
+                    .lerp(gesture_delta / (now - last).max(delta_secs), 0.5),
+                None => gesture_delta / delta_secs,
+            };
+            anim.last_gesture_time = Some(now);
+            continue;
+        }
+
+        if anim.held {
+            // The finger is holding the content still, so the fling speed dies away.
+            anim.velocity *= decay;
+            continue;
+        }
+
+        if let Some(last) = anim.last_gesture_time {
+            if !anim.released && now - last < GESTURE_IDLE_TIME {
+                // The gesture may still be in progress, so don't start the fling yet.
+                continue;
+            }
+            anim.last_gesture_time = None;
+            anim.released = false;
+        }
+
+        if let Some(target) = anim.target {
+            let target = target.clamp(Vec2::ZERO, max_offset);
+            let t = if smooth.ease_time > 0. {
+                1. - (-delta_secs / smooth.ease_time).exp()
+            } else {
+                1.
+            };
+            let mut new_offset = offset.lerp(target, t);
+            if new_offset.distance(target) < SETTLE_DISTANCE {
+                new_offset = target;
+                anim.target = None;
+            }
+            scroll_pos.offset_x = new_offset.x;
+            scroll_pos.offset_y = new_offset.y;
+        } else if anim.velocity != Vec2::ZERO {
+            if anim.velocity.length() < smooth.min_fling_speed {
+                anim.velocity = Vec2::ZERO;
+                continue;
+            }
+            let unclamped = offset + anim.velocity * delta_secs;
+            let new_offset = unclamped.clamp(Vec2::ZERO, max_offset);
+            // Flings stop dead at the edges of the content.
+            if new_offset.x != unclamped.x {
+                anim.velocity.x = 0.;
+            }
+            if new_offset.y != unclamped.y {
+                anim.velocity.y = 0.;
+            }
+            anim.velocity *= decay;
+            scroll_pos.offset_x = new_offset.x;
+            scroll_pos.offset_y = new_offset.y;
+        }
+    }
+}
+
+pub struct CoreScrollAreaPlugin;
+
+impl Plugin for CoreScrollAreaPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(scroll_area_on_pointer_down)
+            .add_observer(scroll_area_on_key_input)
+            .init_resource::<FocusScrollSettings>()
+            .add_observer(scroll_area_on_touch_drag_start)
+            .add_observer(scroll_area_on_touch_drag)
+            .add_observer(scroll_area_on_touch_drag_end)
+            .add_systems(
+                Update,
+                (scroll_area_on_mouse_wheel, animate_scroll_areas).chain(),
+            )
+            .add_systems(PostUpdate, scroll_focus_into_view.before(UiSystem::Layout));
+        if !app.is_plugin_added::<ScrollLinkPlugin>() {
+            app.add_plugins(ScrollLinkPlugin);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    const FRAME: Duration = Duration::from_nanos(16_666_667);
+
+    /// Spawn a smoothly scrolling area which can scroll vertically by `range` logical pixels.
+    fn spawn_scroll_area(world: &mut World, range: f32) -> Entity {
+        world
+            .spawn((
+                SmoothScroll::default(),
+                ScrollPosition::default(),
+                ComputedNode {
+                    size: Vec2::new(100., 100.),
+                    content_size: Vec2::new(100., 100. + range),
+                    inverse_scale_factor: 1.,
+                    ..default()
+                },
+            ))
+            .id()
+    }
+
+    /// Advance the time by one frame and run the animation.
+    fn step(world: &mut World) {
+        world.resource_mut::<Time>().advance_by(FRAME);
+        world.run_system_once(animate_scroll_areas).unwrap();
+    }
+
+    fn offset(world: &World, area: Entity) -> f32 {
+        world.get::<ScrollPosition>(area).unwrap().offset_y
+    }
+
+    /// Move the content directly, as a trackpad gesture does.
+    fn gesture(world: &mut World, area: Entity, delta: f32) {
+        world.get_mut::<ScrollPosition>(area).unwrap().offset_y += delta;
+        world
+            .get_mut::<ScrollAnimation>(area)
+            .unwrap()
+            .track_gesture(Vec2::new(0., delta));
+    }
+
+    fn animation(world: &World, area: Entity) -> &ScrollAnimation {
+        world.get::<ScrollAnimation>(area).unwrap()
+    }
+
+    #[test]
+    fn easing_settles_on_target() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let area = spawn_scroll_area(&mut world, 1000.);
+        world
+            .get_mut::<ScrollAnimation>(area)
+            .unwrap()
+            .ease_to(Vec2::new(0., 200.));
+
+        step(&mut world);
+        let first = offset(&world, area);
+        assert!(first > 0. && first < 200.);
+        for _ in 0..60 {
+            step(&mut world);
+        }
+        assert_eq!(offset(&world, area), 200.);
+        assert!(!animation(&world, area).is_animating());
+    }
+
+    #[test]
+    fn fling_waits_for_gesture_to_end_and_decays() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let area = spawn_scroll_area(&mut world, 10_000.);
+
+        // Trackpad events which arrive every other frame don't start a fling in between.
+        for _ in 0..5 {
+            gesture(&mut world, area, 20.);
+            step(&mut world);
+            let between = offset(&world, area);
+            step(&mut world);
+            assert_eq!(offset(&world, area), between);
+        }
+        assert!(!animation(&world, area).is_animating());
+
+        // Once the gesture has been idle for long enough, the content keeps moving at the speed
+        // of the gesture: 20 pixels every two frames.
+        let before = offset(&world, area);
+        for _ in 0..5 {
+            step(&mut world);
+        }
+        let moved = offset(&world, area) - before;
+        assert!(animation(&world, area).is_animating());
+        assert!(moved > 0. && moved < 60.);
+        let speed = animation(&world, area).velocity.y;
+        assert!(
+            speed > 400. && speed < 600.,
+            "unexpected fling speed {speed}"
+        );
+
+        // Friction brings the fling to a stop.
+        for _ in 0..300 {
+            step(&mut world);
+        }
+        assert!(!animation(&world, area).is_animating());
+        let rest = offset(&world, area);
+        step(&mut world);
+        assert_eq!(offset(&world, area), rest);
+    }
+
+    #[test]
+    fn fling_stops_at_edge() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let area = spawn_scroll_area(&mut world, 100.);
+
+        for _ in 0..3 {
+            gesture(&mut world, area, 20.);
+            step(&mut world);
+        }
+        for _ in 0..120 {
+            step(&mut world);
+        }
+        assert_eq!(offset(&world, area), 100.);
+        assert!(!animation(&world, area).is_animating());
+    }
+}
@@ -1,23 +1,64 @@
-use bevy::prelude::*;
+use accesskit::{Action, NodeId, Role};
+use bevy::{
+    a11y::{AccessibilityNode, ActionRequest},
+    ecs::system::SystemId,
+    input::{keyboard::KeyboardInput, ButtonState},
+    picking::pointer::{PointerId, PointerLocation, PointerPress},
+    prelude::*,
+};
 
-#[derive(Debug, Default, Clone, Copy, PartialEq)]
-pub enum Orientation {
-    Horizontal,
-    #[default]
-    Vertical,
-}
+use crate::{
+    accessibility::{accessible_node, action_target},
+    core_scroll_area::ScrollAnimation,
+    repeat::{RepeatTimer, REPEAT_DELAY, REPEAT_INTERVAL},
+    scroll_link::ScrollLinkPlugin,
+    scrollbar_visibility::update_scrollbar_visibility,
+    track::{pointer_local_position, TrackClick},
+    ButtonAutoRepeat, ButtonClicked, CoreButton, DragPhase, InputSource, Modifiers, Orientation,
+    ValueChange, ValueDrag,
+};
 
-/// A headless scrollbar widget, which can be used to build custom scrollbars. This component emits
-/// [`ValueChange`] events when the scrollbar value changes.
+/// A headless scrollbar widget, which can be used to build custom scrollbars. Whenever the user
+/// scrolls with the scrollbar, a [`ValueChange<f32>`] is passed to the `on_change` callback, or,
+/// if that is `None`, emitted as an event. Its value is the new scroll offset along the
+/// scrollbar's axis, and its old value is the offset the target was scrolling to beforehand.
+///
+/// By default the scrollbar also updates the [`ScrollPosition`] of its target. If `controlled`
+/// is true, the scrollbar only reports the requested offset, and it is the receiver's
+/// responsibility to update the scroll position. This is useful when the offset needs to be
+/// validated, or when it is shared between several scrolling panes. In either case, the thumb
+/// is positioned according to the target's `ScrollPosition` and layout.
 ///
-/// Unlike sliders, scrollbars don't have an [`AccessibilityNode`] component, nor can they have
-/// keyboard focus. This is because scrollbars are usually used in conjunction with a scrollable
-/// container, which is itself accessible and focusable.
+/// Scrollbars can't have keyboard focus, since they are usually used in conjunction with a
+/// scrollable container, which is itself focusable. The scrollbar's [`AccessibilityNode`]
+/// reports the scroll offset and range, and refers to the target as the element it controls.
+/// If the target has an `AccessibilityNode` of its own, its scroll properties are updated too.
 ///
 /// A scrollbar can have any number of child entities, but one entity must be the scrollbar
-/// thumb, which is marked with the [`CoreScrollbarThumb`] component. Other children are ignored.
+/// thumb, which is marked with the [`CoreScrollbarThumb`] component. Children marked with
+/// [`CoreScrollbarStepButton`] are step buttons, which are placed at the ends of the scrollbar;
+/// the space they occupy is excluded from the track. Other children are ignored.
+///
+/// Pressing on the scrollbar track, outside of the thumb, behaves according to the
+/// `track_click` field. Holding down the Alt (Option) key while pressing selects the other
+/// behavior.
+///
+/// Dragging the thumb emits a [`ValueDrag`] event, containing the scroll offset along the
+/// scrollbar's axis, when the drag starts and when it ends. A press on the track starts the
+/// gesture too, which ends when the pointer is released or, if the press turns into a drag,
+/// when the drag ends. Pressing Escape during the gesture cancels it and restores the scroll
+/// offset from before it started.
+///
+/// The `ScrollUp` and `ScrollDown` accessibility actions (or `ScrollLeft` and `ScrollRight` for
+/// horizontal scrollbars), aimed at either the scrollbar or its target, scroll by one page.
+///
+/// If the target has a [`SmoothScroll`](crate::SmoothScroll) component, paging is animated.
+///
+/// Adding a [`ScrollbarAutoHide`](crate::ScrollbarAutoHide) component makes the scrollbar report
+/// when it should be shown or hidden.
 #[derive(Component, Debug)]
 #[require(ScrollbarDragState)]
+#[require(AccessibilityNode(accessible_node(Role::ScrollBar, &[])))]
 pub struct CoreScrollbar {
     /// Entity being scrolled.
     pub target: Entity,
@@ -25,6 +66,13 @@ pub struct CoreScrollbar {
     pub orientation: Orientation,
     /// Minimum size of the scrollbar thumb, in pixel units.
     pub min_thumb_size: f32,
+    /// What happens when the user presses on the scrollbar track.
+    pub track_click: TrackClick,
+    /// Whether the app, rather than the scrollbar, updates the target's scroll position.
+    pub controlled: bool,
+    /// Callback which is run with the requested scroll offset whenever the user scrolls with the
+    /// scrollbar.
+    pub on_change: Option<SystemId<In<ValueChange<f32>>>>,
 }
 
 /// Marker component to indicate that the entity is a scrollbar thumb. This should be a child
@@ -38,6 +86,47 @@ impl CoreScrollbar {
             target,
             orientation,
             min_thumb_size,
+            track_click: TrackClick::default(),
+            controlled: false,
+            on_change: None,
+        }
+    }
+}
+
+/// Which way a [`CoreScrollbarStepButton`] scrolls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepDirection {
+    /// Scroll toward the start of the content (up or left).
+    Backward,
+    /// Scroll toward the end of the content (down or right).
+    Forward,
+}
+
+/// Component for the step (arrow) buttons of a scrollbar. This should be a child of the scrollbar
+/// entity; it adds a [`CoreButton`] with a [`ButtonAutoRepeat`] component to the entity, which is
+/// used to handle the pointer interactions. Each click of the button, including the first one
+/// when it is pressed, scrolls the target by `step` logical pixels. Repeating pauses while the
+/// pointer is outside of the button, and the timing can be adjusted by replacing the
+/// `ButtonAutoRepeat` component.
+///
+/// Backward buttons are assumed to sit at the start of the scrollbar, and forward buttons at the
+/// end, so their sizes are subtracted from the corresponding end of the track. Step buttons don't
+/// take keyboard focus unless they are given a `TabIndex`.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(CoreButton, ButtonAutoRepeat)]
+pub struct CoreScrollbarStepButton {
+    /// Which way the button scrolls.
+    pub direction: StepDirection,
+    /// Distance to scroll for each step, in logical pixels.
+    pub step: f32,
+}
+
+impl CoreScrollbarStepButton {
+    /// Signed scroll distance for a single step.
+    fn delta(&self) -> f32 {
+        match self.direction {
+            StepDirection::Backward => -self.step,
+            StepDirection::Forward => self.step,
         }
     }
 }
@@ -47,46 +136,358 @@ impl CoreScrollbar {
 pub struct ScrollbarDragState {
     /// Whether the scrollbar is currently being dragged.
     dragging: bool,
+    /// Whether a gesture, which was reported by a [`ValueDrag`] start event, is in progress.
+    /// Gestures start when the thumb is dragged or the track is pressed.
+    gesture: bool,
     /// The value of the scrollbar when dragging started.
     offset: f32,
+    /// The value of the scrollbar before the gesture began. This is restored if the drag is
+    /// cancelled.
+    start_offset: f32,
+    /// The press on the scrollbar track which is currently in progress, if any.
+    track_press: Option<TrackPress>,
+    /// Timer used to auto-repeat paging while the track is held down.
+    repeat: RepeatTimer,
+}
+
+impl ScrollbarDragState {
+    /// Whether the thumb is being dragged or the track is being pressed.
+    pub(crate) fn is_pressed(&self) -> bool {
+        self.dragging || self.track_press.is_some()
+    }
+}
+
+/// A press on the scrollbar track, outside of the thumb.
+#[derive(Debug, Clone, Copy)]
+struct TrackPress {
+    /// The pointer which pressed on the track.
+    pointer: PointerId,
+    /// The behavior which was selected when the track was pressed.
+    click: TrackClick,
+}
+
+/// Measurements of a scrollbar and its scrolling target, in logical pixels.
+struct ScrollbarTrack {
+    /// Size of the visible scrolling area.
+    visible_size: Vec2,
+    /// Size of the scrolling content.
+    content_size: Vec2,
+    /// Distance from the start of the scrollbar to the start of the track, along its axis.
+    track_start: f32,
+    /// Length of the scrollbar track, along its axis.
+    track_length: f32,
+    /// Minimum size of the scrollbar thumb.
+    min_thumb_size: f32,
+}
+
+impl ScrollbarTrack {
+    /// Measure the scrollbar. `insets` is the space taken up by step buttons at the start and
+    /// end of the scrollbar, as returned by [`step_button_insets`].
+    fn new(
+        scrollbar: &CoreScrollbar,
+        node: &ComputedNode,
+        scroll_content: &ComputedNode,
+        insets: (f32, f32),
+    ) -> Self {
+        let length = scrollbar
+            .orientation
+            .axis(node.size() * node.inverse_scale_factor);
+        Self {
+            visible_size: scroll_content.size() * scroll_content.inverse_scale_factor,
+            content_size: scroll_content.content_size() * scroll_content.inverse_scale_factor,
+            track_start: insets.0,
+            track_length: (length - insets.0 - insets.1).max(0.),
+            min_thumb_size: scrollbar.min_thumb_size,
+        }
+    }
+
+    /// The maximum scroll offset along the given axis.
+    fn range(&self, orientation: Orientation) -> f32 {
+        (orientation.axis(self.content_size) - orientation.axis(self.visible_size)).max(0.)
+    }
+
+    /// Compute the position and size of the thumb along the track, for the given scroll offset.
+    /// The position is relative to the start of the track.
+    fn thumb_extent(&self, orientation: Orientation, offset: f32) -> (f32, f32) {
+        let visible_size = orientation.axis(self.visible_size);
+        let content_size = orientation.axis(self.content_size);
+        let track_length = self.track_length;
+        if content_size > visible_size {
+            let thumb_size = (track_length * visible_size / content_size)
+                .max(self.min_thumb_size)
+                .min(track_length);
+            let thumb_pos = offset * (track_length - thumb_size) / (content_size - visible_size);
+            (thumb_pos, thumb_size)
+        } else {
+            (0., track_length)
+        }
+    }
+
+    /// Compute the scroll offset after moving one page from `offset` toward `hit_pos`, a
+    /// position along the scrollbar. Returns `None` if the thumb already covers the hit position.
+    fn page_toward(&self, orientation: Orientation, offset: f32, hit_pos: f32) -> Option<f32> {
+        let hit_pos = hit_pos - self.track_start;
+        let (thumb_pos, thumb_size) = self.thumb_extent(orientation, offset);
+        if hit_pos < thumb_pos {
+            Some(self.scroll_pages(orientation, offset, -1.))
+        } else if hit_pos >= thumb_pos + thumb_size {
+            Some(self.scroll_pages(orientation, offset, 1.))
+        } else {
+            None
+        }
+    }
+
+    /// Compute the scroll offset after moving from `offset` by the given number of pages,
+    /// clamped to the scrolling range.
+    fn scroll_pages(&self, orientation: Orientation, offset: f32, pages: f32) -> f32 {
+        let page = orientation.axis(self.visible_size);
+        self.scroll_by(orientation, offset, page * pages)
+    }
+
+    /// Compute the scroll offset after moving from `offset` by `delta` logical pixels, clamped
+    /// to the scrolling range.
+    fn scroll_by(&self, orientation: Orientation, offset: f32, delta: f32) -> f32 {
+        (offset + delta).clamp(0., self.range(orientation))
+    }
+
+    /// Compute the scroll offset at which the thumb is centered on `hit_pos`, a position along
+    /// the scrollbar.
+    fn jump_to(&self, orientation: Orientation, hit_pos: f32) -> f32 {
+        let hit_pos = hit_pos - self.track_start;
+        let range = self.range(orientation);
+        let (_, thumb_size) = self.thumb_extent(orientation, 0.);
+        let travel = self.track_length - thumb_size;
+        let new_offset = if travel > 0. {
+            (hit_pos - thumb_size * 0.5) * range / travel
+        } else {
+            0.
+        };
+        new_offset.clamp(0., range)
+    }
+}
+
+/// Returns the space occupied by step buttons at the start and end of a scrollbar, along its
+/// axis, in logical pixels.
+fn step_button_insets(
+    orientation: Orientation,
+    children: Option<&Children>,
+    q_step: &Query<(&CoreScrollbarStepButton, &ComputedNode)>,
+) -> (f32, f32) {
+    let mut insets = (0., 0.);
+    for (button, node) in q_step.iter_many(children.into_iter().flatten()) {
+        let size = orientation.axis(node.size() * node.inverse_scale_factor);
+        match button.direction {
+            StepDirection::Backward => insets.0 += size,
+            StepDirection::Forward => insets.1 += size,
+        }
+    }
+    insets
+}
+
+pub(crate) fn scroll_offset(orientation: Orientation, scroll_pos: &ScrollPosition) -> f32 {
+    match orientation {
+        Orientation::Horizontal => scroll_pos.offset_x,
+        Orientation::Vertical => scroll_pos.offset_y,
+    }
+}
+
+pub(crate) fn set_scroll_offset(
+    orientation: Orientation,
+    scroll_pos: &mut ScrollPosition,
+    offset: f32,
+) {
+    match orientation {
+        Orientation::Horizontal => scroll_pos.offset_x = offset,
+        Orientation::Vertical => scroll_pos.offset_y = offset,
+    }
+}
+
+/// Returns the scroll offset at which the target will come to rest, taking any easing animation
+/// into account.
+fn destination_offset(
+    orientation: Orientation,
+    scroll_pos: &ScrollPosition,
+    anim: Option<&ScrollAnimation>,
+) -> f32 {
+    match anim {
+        Some(anim) => orientation.axis(anim.destination(scroll_pos)),
+        None => scroll_offset(orientation, scroll_pos),
+    }
+}
+
+/// How a scroll requested by the user should be carried out and reported.
+#[derive(Clone, Copy)]
+struct ScrollRequest {
+    /// The input which caused the scroll.
+    input: InputSource,
+    /// The modifier keys which were held down.
+    modifiers: Modifiers,
+    /// Whether the target should ease toward the new offset, if it scrolls smoothly.
+    animate: bool,
+}
+
+impl ScrollRequest {
+    fn new(input: InputSource, keys: &ButtonInput<KeyCode>, animate: bool) -> Self {
+        Self {
+            input,
+            modifiers: Modifiers::from_keys(keys),
+            animate,
+        }
+    }
+}
+
+/// Request that the scrollbar's target scroll to `offset`, and report the change to the
+/// scrollbar's `on_change` callback or as a [`ValueChange`] event. Controlled scrollbars only
+/// report the change.
+///
+/// If the request is animated and the target scrolls smoothly, the target eases toward the new
+/// offset; otherwise it moves there immediately, and any animation in progress is stopped.
+fn scroll_target_to(
+    commands: &mut Commands,
+    scrollbar_id: Entity,
+    scrollbar: &CoreScrollbar,
+    scroll_pos: &mut ScrollPosition,
+    anim: Option<&mut ScrollAnimation>,
+    offset: f32,
+    request: ScrollRequest,
+) {
+    let orientation = scrollbar.orientation;
+    let old_offset = destination_offset(orientation, scroll_pos, anim.as_deref());
+    if offset == old_offset {
+        return;
+    }
+
+    ValueChange {
+        value: offset,
+        old_value: Some(old_offset),
+        source: scrollbar_id,
+        input: request.input,
+        modifiers: request.modifiers,
+    }
+    .emit(commands, scrollbar.on_change);
+    if scrollbar.controlled {
+        return;
+    }
+
+    match anim {
+        Some(anim) if request.animate => {
+            let mut destination = anim.destination(scroll_pos);
+            match orientation {
+                Orientation::Horizontal => destination.x = offset,
+                Orientation::Vertical => destination.y = offset,
+            }
+            anim.ease_to(destination);
+        }
+        anim => {
+            if let Some(anim) = anim {
+                anim.stop();
+            }
+            set_scroll_offset(orientation, scroll_pos, offset);
+        }
+    }
 }
 
 pub(crate) fn scrollbar_on_pointer_down(
     mut trigger: Trigger<Pointer<Pressed>>,
     q_thumb: Query<&ChildOf, With<CoreScrollbarThumb>>,
-    mut q_scrollbar: Query<(&CoreScrollbar, &GlobalTransform)>,
-    mut _q_scroll_pos: Query<(&mut ScrollPosition, &ComputedNode), Without<CoreScrollbar>>,
+    mut q_scrollbar: Query<(
+        &CoreScrollbar,
+        &ComputedNode,
+        &GlobalTransform,
+        Option<&Children>,
+        &mut ScrollbarDragState,
+    )>,
+    q_step: Query<(&CoreScrollbarStepButton, &ComputedNode)>,
+    mut q_scroll_pos: Query<
+        (
+            &mut ScrollPosition,
+            &ComputedNode,
+            Option<&mut ScrollAnimation>,
+        ),
+        Without<CoreScrollbar>,
+    >,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
 ) {
+    let scrollbar_id = trigger.target();
     if q_thumb.contains(trigger.target()) {
         // If they click on the thumb, do nothing. This will be handled by the drag event.
         trigger.propagate(false);
-    } else if let Ok((_scrollbar, _transform)) = q_scrollbar.get_mut(trigger.target()) {
+    } else if let Ok((scrollbar, node, transform, children, mut drag)) =
+        q_scrollbar.get_mut(trigger.target())
+    {
         // If they click on the scrollbar track, page up or down.
         trigger.propagate(false);
-        // TODO: Finish this once we figure out how to get the local click coordinates.
-
-        // let Ok((mut scroll_pos, scroll_content)) = q_scroll_pos.get_mut(scrollbar.target) else {
-        //     return;
-        // };
-        // // Rather than scanning for the thumb entity, just calculate where the thumb will be.
-        // let visible_size = scroll_content.size() * scroll_content.inverse_scale_factor;
-        // let content_size = scroll_content.content_size() * scroll_content.inverse_scale_factor;
-        // info!(
-        //     "translation: {:?} location {:?}",
-        //     transform.translation(),
-        //     trigger.event().pointer_location.position
-        // );
-        // match scrollbar.orientation {
-        //     Orientation::Horizontal => {
-        //         // let hit_pos = trigger.event().pointer_location.position.x
-        //         //     - transform.translation().x * scroll_content.inverse_scale_factor;
-        //     }
-        //     Orientation::Vertical => {
-        //         // let hit_pos = trigger.event().pointer_location.position.y
-        //         //     - transform.translation().y * scroll_content.inverse_scale_factor;
-        //         // info!("scrollbar_on_pointer_down: {hit_pos}");
-        //     }
-        // }
+        if trigger.event().button != PointerButton::Primary {
+            return;
+        }
+
+        let Ok((mut scroll_pos, scroll_content, mut anim)) = q_scroll_pos.get_mut(scrollbar.target)
+        else {
+            return;
+        };
+
+        // The press starts the gesture, so that the new offset is a preview.
+        drag.start_offset = destination_offset(scrollbar.orientation, &scroll_pos, anim.as_deref());
+        drag.gesture = true;
+        commands.trigger_targets(
+            ValueDrag {
+                phase: DragPhase::Start,
+                value: drag.start_offset,
+            },
+            scrollbar_id,
+        );
+
+        let track_click = if keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]) {
+            scrollbar.track_click.inverted()
+        } else {
+            scrollbar.track_click
+        };
+
+        let hit_pos = scrollbar.orientation.axis(pointer_local_position(
+            node,
+            transform,
+            trigger.event().pointer_location.position,
+        ));
+        let insets = step_button_insets(scrollbar.orientation, children, &q_step);
+        let track = ScrollbarTrack::new(scrollbar, node, scroll_content, insets);
+        let input = InputSource::primary(trigger.event().pointer_id);
+        match track_click {
+            TrackClick::Page => {
+                let offset =
+                    destination_offset(scrollbar.orientation, &scroll_pos, anim.as_deref());
+                if let Some(new_offset) = track.page_toward(scrollbar.orientation, offset, hit_pos)
+                {
+                    scroll_target_to(
+                        &mut commands,
+                        scrollbar_id,
+                        scrollbar,
+                        &mut scroll_pos,
+                        anim.as_deref_mut(),
+                        new_offset,
+                        ScrollRequest::new(input, &keys, true),
+                    );
+                }
+            }
+            TrackClick::Jump => {
+                let new_offset = track.jump_to(scrollbar.orientation, hit_pos);
+                scroll_target_to(
+                    &mut commands,
+                    scrollbar_id,
+                    scrollbar,
+                    &mut scroll_pos,
+                    anim.as_deref_mut(),
+                    new_offset,
+                    ScrollRequest::new(input, &keys, false),
+                );
+            }
+        }
+
+        drag.track_press = Some(TrackPress {
+            pointer: trigger.event().pointer_id,
+            click: track_click,
+        });
+        drag.repeat.reset();
     }
 }
 
@@ -94,17 +495,42 @@ pub(crate) fn scrollbar_on_drag_start(
     mut trigger: Trigger<Pointer<DragStart>>,
     q_thumb: Query<&ChildOf, With<CoreScrollbarThumb>>,
     mut q_scrollbar: Query<(&CoreScrollbar, &mut ScrollbarDragState)>,
-    q_scroll_area: Query<&ScrollPosition>,
+    mut q_scroll_area: Query<(&ScrollPosition, Option<&mut ScrollAnimation>)>,
+    mut commands: Commands,
 ) {
     if let Ok(ChildOf(thumb_parent)) = q_thumb.get(trigger.target()) {
         trigger.propagate(false);
         if let Ok((scrollbar, mut drag)) = q_scrollbar.get_mut(*thumb_parent) {
-            if let Ok(scroll_area) = q_scroll_area.get(scrollbar.target) {
+            if let Ok((scroll_area, anim)) = q_scroll_area.get_mut(scrollbar.target) {
+                // The thumb follows the pointer exactly, so stop any animation.
+                if let Some(mut anim) = anim {
+                    anim.stop();
+                }
                 drag.dragging = true;
-                drag.offset = match scrollbar.orientation {
-                    Orientation::Horizontal => scroll_area.offset_x,
-                    Orientation::Vertical => scroll_area.offset_y,
-                };
+                drag.gesture = true;
+                drag.offset = scroll_offset(scrollbar.orientation, scroll_area);
+                drag.start_offset = drag.offset;
+                commands.trigger_targets(
+                    ValueDrag {
+                        phase: DragPhase::Start,
+                        value: drag.start_offset,
+                    },
+                    *thumb_parent,
+                );
+            }
+        }
+    } else if let Ok((scrollbar, mut drag)) = q_scrollbar.get_mut(trigger.target()) {
+        // If the track was pressed in "jump" mode, then the thumb has already moved to the
+        // pointer, so continue dragging from there. The gesture started with the press.
+        trigger.propagate(false);
+        if let Some(TrackPress {
+            click: TrackClick::Jump,
+            ..
+        }) = drag.track_press
+        {
+            if let Ok((scroll_area, _)) = q_scroll_area.get(scrollbar.target) {
+                drag.dragging = true;
+                drag.offset = scroll_offset(scrollbar.orientation, scroll_area);
             }
         }
     }
@@ -112,45 +538,100 @@ pub(crate) fn scrollbar_on_drag_start(
 
 pub(crate) fn scrollbar_on_drag(
     mut trigger: Trigger<Pointer<Drag>>,
-    mut q_scrollbar: Query<(&ComputedNode, &CoreScrollbar, &mut ScrollbarDragState)>,
+    mut q_scrollbar: Query<(
+        &ComputedNode,
+        &CoreScrollbar,
+        Option<&Children>,
+        &mut ScrollbarDragState,
+    )>,
+    q_step: Query<(&CoreScrollbarStepButton, &ComputedNode)>,
     mut q_scroll_pos: Query<(&mut ScrollPosition, &ComputedNode), Without<CoreScrollbar>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
 ) {
-    if let Ok((node, scrollbar, drag)) = q_scrollbar.get_mut(trigger.target()) {
+    let scrollbar_id = trigger.target();
+    if let Ok((node, scrollbar, children, drag)) = q_scrollbar.get_mut(scrollbar_id) {
         trigger.propagate(false);
         let Ok((mut scroll_pos, scroll_content)) = q_scroll_pos.get_mut(scrollbar.target) else {
             return;
         };
 
         if drag.dragging {
-            let distance = trigger.event().distance;
-            let visible_size = scroll_content.size() * scroll_content.inverse_scale_factor;
-            let content_size = scroll_content.content_size() * scroll_content.inverse_scale_factor;
-            match scrollbar.orientation {
-                Orientation::Horizontal => {
-                    let range = (content_size.x - visible_size.x).max(0.);
-                    let scrollbar_width = (node.size().x * node.inverse_scale_factor
-                        - scrollbar.min_thumb_size)
-                        .max(1.0);
-                    scroll_pos.offset_x = if range > 0. {
-                        (drag.offset + (distance.x * content_size.x) / scrollbar_width)
-                            .clamp(0., range)
-                    } else {
-                        0.
-                    }
-                }
-                Orientation::Vertical => {
-                    let range = (content_size.y - visible_size.y).max(0.);
-                    let scrollbar_height = (node.size().y * node.inverse_scale_factor
-                        - scrollbar.min_thumb_size)
-                        .max(1.0);
-                    scroll_pos.offset_y = if range > 0. {
-                        (drag.offset + (distance.y * content_size.y) / scrollbar_height)
-                            .clamp(0., range)
-                    } else {
-                        0.
-                    }
-                }
+            let orientation = scrollbar.orientation;
+            let distance = orientation.axis(trigger.event().distance);
+            let insets = step_button_insets(orientation, children, &q_step);
+            let track = ScrollbarTrack::new(scrollbar, node, scroll_content, insets);
+            let range = track.range(orientation);
+            // The thumb moves through the length of the track which it doesn't cover, so that it
+            // stays under the pointer.
+            let (_, thumb_size) = track.thumb_extent(orientation, drag.offset);
+            let travel = track.track_length - thumb_size;
+            let new_offset = if range > 0. && travel > 0. {
+                (drag.offset + distance * range / travel).clamp(0., range)
+            } else {
+                drag.offset
             };
+            scroll_target_to(
+                &mut commands,
+                scrollbar_id,
+                scrollbar,
+                &mut scroll_pos,
+                None,
+                new_offset,
+                ScrollRequest::new(
+                    InputSource::Pointer {
+                        pointer: trigger.event().pointer_id,
+                        button: trigger.event().button,
+                    },
+                    &keys,
+                    false,
+                ),
+            );
+        }
+    }
+}
+
+/// End the scrollbar's gesture, if one is in progress, and report the final scroll offset.
+fn commit_scrollbar_gesture(
+    commands: &mut Commands,
+    scrollbar_id: Entity,
+    scrollbar: &CoreScrollbar,
+    drag: &mut ScrollbarDragState,
+    q_scroll_area: &Query<(&ScrollPosition, Option<&ScrollAnimation>)>,
+) {
+    drag.dragging = false;
+    if !drag.gesture {
+        return;
+    }
+    drag.gesture = false;
+    if let Ok((scroll_pos, anim)) = q_scroll_area.get(scrollbar.target) {
+        commands.trigger_targets(
+            ValueDrag {
+                phase: DragPhase::Commit,
+                value: destination_offset(scrollbar.orientation, scroll_pos, anim),
+            },
+            scrollbar_id,
+        );
+    }
+}
+
+pub(crate) fn scrollbar_on_pointer_up(
+    mut trigger: Trigger<Pointer<Released>>,
+    mut q_scrollbar: Query<(&CoreScrollbar, &mut ScrollbarDragState)>,
+    q_scroll_area: Query<(&ScrollPosition, Option<&ScrollAnimation>)>,
+    mut commands: Commands,
+) {
+    if let Ok((scrollbar, mut drag)) = q_scrollbar.get_mut(trigger.target()) {
+        trigger.propagate(false);
+        // A track press which didn't turn into a drag ends the gesture when it is released.
+        if !drag.dragging {
+            commit_scrollbar_gesture(
+                &mut commands,
+                trigger.target(),
+                scrollbar,
+                &mut drag,
+                &q_scroll_area,
+            );
         }
     }
 }
@@ -158,74 +639,304 @@ pub(crate) fn scrollbar_on_drag(
 pub(crate) fn scrollbar_on_drag_end(
     mut trigger: Trigger<Pointer<DragEnd>>,
     mut q_scrollbar: Query<(&CoreScrollbar, &mut ScrollbarDragState)>,
+    q_scroll_area: Query<(&ScrollPosition, Option<&ScrollAnimation>)>,
+    mut commands: Commands,
 ) {
-    if let Ok((_scrollbar, mut drag)) = q_scrollbar.get_mut(trigger.target()) {
+    if let Ok((scrollbar, mut drag)) = q_scrollbar.get_mut(trigger.target()) {
         trigger.propagate(false);
-        if drag.dragging {
-            drag.dragging = false;
+        commit_scrollbar_gesture(
+            &mut commands,
+            trigger.target(),
+            scrollbar,
+            &mut drag,
+            &q_scroll_area,
+        );
+    }
+}
+
+fn scrollbar_cancel_on_escape(
+    mut key_events: EventReader<KeyboardInput>,
+    mut q_scrollbar: Query<(Entity, &CoreScrollbar, &mut ScrollbarDragState)>,
+    mut q_scroll_pos: Query<
+        (&mut ScrollPosition, Option<&mut ScrollAnimation>),
+        Without<CoreScrollbar>,
+    >,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+) {
+    // Scrollbars can't be focused, so look at the keyboard input directly.
+    if !key_events
+        .read()
+        .any(|event| event.state == ButtonState::Pressed && event.key_code == KeyCode::Escape)
+    {
+        return;
+    }
+
+    for (scrollbar_id, scrollbar, mut drag) in q_scrollbar.iter_mut() {
+        if !drag.gesture {
+            continue;
+        }
+        // Cancel the gesture, and restore the original scroll position.
+        drag.dragging = false;
+        drag.gesture = false;
+        drag.track_press = None;
+        if let Ok((mut scroll_pos, mut anim)) = q_scroll_pos.get_mut(scrollbar.target) {
+            scroll_target_to(
+                &mut commands,
+                scrollbar_id,
+                scrollbar,
+                &mut scroll_pos,
+                anim.as_deref_mut(),
+                drag.start_offset,
+                ScrollRequest::new(InputSource::Keyboard, &keys, false),
+            );
         }
+        commands.trigger_targets(
+            ValueDrag {
+                phase: DragPhase::Cancel,
+                value: drag.start_offset,
+            },
+            scrollbar_id,
+        );
     }
 }
 
-fn update_scrollbar_thumb(
-    q_scroll_area: Query<(&ScrollPosition, &ComputedNode)>,
-    q_scrollbar: Query<(&CoreScrollbar, &ComputedNode, &Children)>,
-    mut q_thumb: Query<&mut Node, With<CoreScrollbarThumb>>,
+#[allow(clippy::type_complexity)]
+fn scrollbar_track_repeat(
+    time: Res<Time>,
+    mut q_scrollbar: Query<(
+        Entity,
+        &CoreScrollbar,
+        &ComputedNode,
+        &GlobalTransform,
+        Option<&Children>,
+        &mut ScrollbarDragState,
+    )>,
+    q_step: Query<(&CoreScrollbarStepButton, &ComputedNode)>,
+    mut q_scroll_pos: Query<
+        (
+            &mut ScrollPosition,
+            &ComputedNode,
+            Option<&mut ScrollAnimation>,
+        ),
+        Without<CoreScrollbar>,
+    >,
+    q_pointers: Query<(&PointerId, &PointerLocation, &PointerPress)>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
 ) {
-    for (scrollbar, scrollbar_node, children) in q_scrollbar.iter() {
-        let Ok(scroll_area) = q_scroll_area.get(scrollbar.target) else {
+    for (scrollbar_id, scrollbar, node, transform, children, mut drag) in q_scrollbar.iter_mut() {
+        let Some(press) = drag.track_press else {
+            continue;
+        };
+
+        // Stop paging once the pointer is released.
+        let Some((_, location, _)) = q_pointers
+            .iter()
+            .find(|(id, _, pressed)| **id == press.pointer && pressed.is_primary_pressed())
+        else {
+            drag.track_press = None;
+            continue;
+        };
+
+        if press.click != TrackClick::Page {
+            continue;
+        }
+
+        let count = drag
+            .repeat
+            .tick(time.delta_secs(), REPEAT_DELAY, REPEAT_INTERVAL);
+        if count == 0 {
+            continue;
+        }
+
+        let (Some(location), Ok((mut scroll_pos, scroll_content, mut anim))) =
+            (location.location(), q_scroll_pos.get_mut(scrollbar.target))
+        else {
             continue;
         };
 
-        // Size of the visible scrolling area.
-        let visible_size = scroll_area.1.size() * scroll_area.1.inverse_scale_factor;
+        // Keep paging until the thumb reaches the pointer.
+        let hit_pos =
+            scrollbar
+                .orientation
+                .axis(pointer_local_position(node, transform, location.position));
+        let insets = step_button_insets(scrollbar.orientation, children, &q_step);
+        let track = ScrollbarTrack::new(scrollbar, node, scroll_content, insets);
+        let request = ScrollRequest::new(InputSource::primary(press.pointer), &keys, true);
+        for _ in 0..count {
+            let offset = destination_offset(scrollbar.orientation, &scroll_pos, anim.as_deref());
+            let Some(new_offset) = track.page_toward(scrollbar.orientation, offset, hit_pos) else {
+                break;
+            };
+            scroll_target_to(
+                &mut commands,
+                scrollbar_id,
+                scrollbar,
+                &mut scroll_pos,
+                anim.as_deref_mut(),
+                new_offset,
+                request,
+            );
+        }
+    }
+}
+
+/// Scroll the target of the scrollbar which owns a step button by `delta` logical pixels.
+#[allow(clippy::type_complexity)]
+fn scrollbar_step(
+    commands: &mut Commands,
+    button_id: Entity,
+    delta: f32,
+    request: ScrollRequest,
+    q_parent: &Query<&ChildOf>,
+    q_scrollbar: &Query<(&CoreScrollbar, &ComputedNode)>,
+    q_scroll_pos: &mut Query<
+        (
+            &mut ScrollPosition,
+            &ComputedNode,
+            Option<&mut ScrollAnimation>,
+        ),
+        Without<CoreScrollbar>,
+    >,
+) {
+    let Some((scrollbar_id, (scrollbar, node))) = q_parent
+        .iter_ancestors(button_id)
+        .find_map(|ancestor| Some((ancestor, q_scrollbar.get(ancestor).ok()?)))
+    else {
+        return;
+    };
+    let Ok((mut scroll_pos, scroll_content, mut anim)) = q_scroll_pos.get_mut(scrollbar.target)
+    else {
+        return;
+    };
+
+    // Stepping doesn't depend on the length of the track.
+    let track = ScrollbarTrack::new(scrollbar, node, scroll_content, (0., 0.));
+    let offset = destination_offset(scrollbar.orientation, &scroll_pos, anim.as_deref());
+    let new_offset = track.scroll_by(scrollbar.orientation, offset, delta);
+    scroll_target_to(
+        commands,
+        scrollbar_id,
+        scrollbar,
+        &mut scroll_pos,
+        anim.as_deref_mut(),
+        new_offset,
+        request,
+    );
+}
+
+/// Scrolls the target of the scrollbar when one of its step buttons is clicked.
+#[allow(clippy::type_complexity)]
+fn step_button_on_click(
+    mut trigger: Trigger<ButtonClicked>,
+    q_button: Query<&CoreScrollbarStepButton>,
+    q_parent: Query<&ChildOf>,
+    q_scrollbar: Query<(&CoreScrollbar, &ComputedNode)>,
+    mut q_scroll_pos: Query<
+        (
+            &mut ScrollPosition,
+            &ComputedNode,
+            Option<&mut ScrollAnimation>,
+        ),
+        Without<CoreScrollbar>,
+    >,
+    mut commands: Commands,
+) {
+    let button_id = trigger.target();
+    let Ok(button) = q_button.get(button_id) else {
+        return;
+    };
+
+    // The button auto-repeats, so each click is one step.
+    trigger.propagate(false);
+    let click = trigger.event();
+    scrollbar_step(
+        &mut commands,
+        button_id,
+        button.delta(),
+        ScrollRequest {
+            input: click.input,
+            modifiers: click.modifiers,
+            animate: true,
+        },
+        &q_parent,
+        &q_scrollbar,
+        &mut q_scroll_pos,
+    );
+}
+
+pub(crate) fn update_scrollbar_thumb(
+    mut q_scroll_area: Query<
+        (
+            &ScrollPosition,
+            &ComputedNode,
+            Option<&mut AccessibilityNode>,
+        ),
+        Without<CoreScrollbar>,
+    >,
+    mut q_scrollbar: Query<(
+        &CoreScrollbar,
+        &ComputedNode,
+        &Children,
+        &mut AccessibilityNode,
+    )>,
+    mut q_thumb: Query<&mut Node, With<CoreScrollbarThumb>>,
+    q_step: Query<(&CoreScrollbarStepButton, &ComputedNode)>,
+) {
+    for (scrollbar, scrollbar_node, children, mut a11y) in q_scrollbar.iter_mut() {
+        let Ok((scroll_pos, scroll_content, target_a11y)) = q_scroll_area.get_mut(scrollbar.target)
+        else {
+            continue;
+        };
 
-        // Size of the scrolling content.
-        let content_size = scroll_area.1.content_size() * scroll_area.1.inverse_scale_factor;
+        let orientation = scrollbar.orientation;
+        let offset = scroll_offset(orientation, scroll_pos);
+        let insets = step_button_insets(orientation, Some(children), &q_step);
+        let track = ScrollbarTrack::new(scrollbar, scrollbar_node, scroll_content, insets);
+        let (thumb_pos, thumb_size) = track.thumb_extent(orientation, offset);
+        let thumb_pos = thumb_pos + track.track_start;
 
-        // Length of the scrollbar track.
-        let track_length = scrollbar_node.size() * scrollbar_node.inverse_scale_factor;
+        // Report the scroll state to assistive technologies.
+        let range = track.range(orientation);
+        a11y.set_orientation(orientation.into());
+        a11y.set_controls(vec![NodeId(scrollbar.target.to_bits())]);
+        a11y.set_numeric_value(offset.into());
+        a11y.set_min_numeric_value(0.);
+        a11y.set_max_numeric_value(range.into());
+        let (scroll_back, scroll_forward) = match orientation {
+            Orientation::Horizontal => (Action::ScrollLeft, Action::ScrollRight),
+            Orientation::Vertical => (Action::ScrollUp, Action::ScrollDown),
+        };
+        a11y.add_action(scroll_back);
+        a11y.add_action(scroll_forward);
+        if let Some(mut target_a11y) = target_a11y {
+            match orientation {
+                Orientation::Horizontal => {
+                    target_a11y.set_scroll_x(offset.into());
+                    target_a11y.set_scroll_x_min(0.);
+                    target_a11y.set_scroll_x_max(range.into());
+                }
+                Orientation::Vertical => {
+                    target_a11y.set_scroll_y(offset.into());
+                    target_a11y.set_scroll_y_min(0.);
+                    target_a11y.set_scroll_y_max(range.into());
+                }
+            }
+            target_a11y.add_action(scroll_back);
+            target_a11y.add_action(scroll_forward);
+        }
 
         for child in children {
             if let Ok(mut thumb) = q_thumb.get_mut(*child) {
                 match scrollbar.orientation {
                     Orientation::Horizontal => {
-                        let thumb_size = if content_size.x > visible_size.x {
-                            (track_length.x * visible_size.x / content_size.x)
-                                .max(scrollbar.min_thumb_size)
-                                .min(track_length.x)
-                        } else {
-                            track_length.x
-                        };
-
-                        let thumb_pos = if content_size.x > visible_size.x {
-                            scroll_area.0.offset_x * (track_length.x - thumb_size)
-                                / (content_size.x - visible_size.x)
-                        } else {
-                            0.
-                        };
-
                         thumb.top = Val::Px(0.);
                         thumb.bottom = Val::Px(0.);
                         thumb.left = Val::Px(thumb_pos);
                         thumb.width = Val::Px(thumb_size);
                     }
                     Orientation::Vertical => {
-                        let thumb_size = if content_size.y > visible_size.y {
-                            (track_length.y * visible_size.y / content_size.y)
-                                .max(scrollbar.min_thumb_size)
-                                .min(track_length.y)
-                        } else {
-                            track_length.y
-                        };
-
-                        let thumb_pos = if content_size.y > visible_size.y {
-                            scroll_area.0.offset_y * (track_length.y - thumb_size)
-                                / (content_size.y - visible_size.y)
-                        } else {
-                            0.
-                        };
-
                         thumb.left = Val::Px(0.);
                         thumb.right = Val::Px(0.);
                         thumb.top = Val::Px(thumb_pos);
@@ -237,14 +948,81 @@ fn update_scrollbar_thumb(
     }
 }
 
+fn scrollbar_on_action_request(
+    mut requests: EventReader<ActionRequest>,
+    q_scrollbar: Query<(Entity, &CoreScrollbar, &ComputedNode)>,
+    mut q_scroll_pos: Query<
+        (
+            &mut ScrollPosition,
+            &ComputedNode,
+            Option<&mut ScrollAnimation>,
+        ),
+        Without<CoreScrollbar>,
+    >,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+) {
+    for request in requests.read() {
+        let Some(target) = action_target(request) else {
+            continue;
+        };
+        for (scrollbar_id, scrollbar, node) in q_scrollbar.iter() {
+            if target != scrollbar_id && target != scrollbar.target {
+                continue;
+            }
+            let pages = match (scrollbar.orientation, request.action) {
+                (Orientation::Vertical, Action::ScrollUp) => -1.,
+                (Orientation::Vertical, Action::ScrollDown) => 1.,
+                (Orientation::Horizontal, Action::ScrollLeft) => -1.,
+                (Orientation::Horizontal, Action::ScrollRight) => 1.,
+                _ => continue,
+            };
+            if let Ok((mut scroll_pos, scroll_content, mut anim)) =
+                q_scroll_pos.get_mut(scrollbar.target)
+            {
+                // Paging doesn't depend on the length of the track, so step buttons don't matter.
+                let track = ScrollbarTrack::new(scrollbar, node, scroll_content, (0., 0.));
+                let offset =
+                    destination_offset(scrollbar.orientation, &scroll_pos, anim.as_deref());
+                let new_offset = track.scroll_pages(scrollbar.orientation, offset, pages);
+                scroll_target_to(
+                    &mut commands,
+                    scrollbar_id,
+                    scrollbar,
+                    &mut scroll_pos,
+                    anim.as_deref_mut(),
+                    new_offset,
+                    ScrollRequest::new(InputSource::Accessibility, &keys, true),
+                );
+            }
+        }
+    }
+}
+
 pub struct CoreScrollbarPlugin;
 
 impl Plugin for CoreScrollbarPlugin {
     fn build(&self, app: &mut App) {
         app.add_observer(scrollbar_on_pointer_down)
+            .add_observer(scrollbar_on_pointer_up)
             .add_observer(scrollbar_on_drag_start)
             .add_observer(scrollbar_on_drag_end)
             .add_observer(scrollbar_on_drag)
-            .add_systems(PostUpdate, update_scrollbar_thumb);
+            .add_observer(step_button_on_click)
+            .add_systems(
+                Update,
+                (
+                    scrollbar_track_repeat,
+                    scrollbar_cancel_on_escape,
+                    scrollbar_on_action_request,
+                ),
+            )
+            .add_systems(
+                PostUpdate,
+                (update_scrollbar_thumb, update_scrollbar_visibility),
+            );
+        if !app.is_plugin_added::<ScrollLinkPlugin>() {
+            app.add_plugins(ScrollLinkPlugin);
+        }
     }
 }
@@ -1,71 +1,304 @@
-use accesskit::{Orientation, Role};
+use std::marker::PhantomData;
+
+use accesskit::{Action, Role};
 use bevy::{
-    a11y::AccessibilityNode,
+    a11y::{AccessibilityNode, ActionRequest},
     ecs::system::SystemId,
     input::{keyboard::KeyboardInput, ButtonState},
     input_focus::{FocusedInput, InputFocus, InputFocusVisible},
     prelude::*,
 };
 
-use crate::{InteractionDisabled, ValueChange};
+use crate::{
+    accessibility::{accessible_node, action_numeric_value, action_target},
+    slider_mapping::{SliderMapping, ValueMapping},
+    slider_snap::{nearest_detent, next_detent, SliderSnap, MAX_INCREMENT_TICKS},
+    slider_value::SliderValue,
+    track::{pointer_local_position, TrackClick},
+    DragPhase, InputSource, InteractionDisabled, Modifiers, Orientation, ValueChange, ValueDrag,
+};
+
+/// Multiplier applied to the slider increment when Shift is held during keyboard input.
+const COARSE_STEP_MULTIPLIER: f64 = 10.0;
+
+/// Multiplier applied to the slider increment when Ctrl is held during keyboard input.
+const FINE_STEP_MULTIPLIER: f64 = 0.1;
 
 /// A headless slider widget, which can be used to build custom sliders. This component emits
 /// [`ValueChange`] events when the slider value changes. Note that the value in the event is
-/// unclamped - the reason is that the receiver may want to quantize or otherwise modify the value
-/// before clamping. It is the receiver's responsibility to update the slider's value when
+/// unclamped - the reason is that the receiver may want to modify the value before clamping.
+/// Values are quantized according to the `snap` field before they are emitted; the list of
+/// tick values, which visual layers can use to draw tick marks, is available from
+/// [`CoreSlider::ticks`]. It is the receiver's responsibility to update the slider's value when
 /// the value change event is received.
+///
+/// Horizontal sliders increase in value from left to right, and vertical sliders increase in
+/// value from bottom to top.
+///
+/// When focused, the slider follows the WAI-ARIA keyboard model: the arrow keys move by
+/// `increment` (ten times as far while Shift is held, or a tenth as far while Ctrl is held),
+/// PageUp and PageDown move by `page_increment`, and Home and End move to the ends of the range.
+/// See <https://www.w3.org/WAI/ARIA/apg/patterns/slider/>
+///
+/// Pressing on the slider outside of the thumb behaves according to the `track_click` field:
+/// either the value jumps to the pointer position, or it moves by `page_increment` toward the
+/// pointer. Holding down the Alt (Option) key while pressing selects the other behavior. In
+/// either case, dragging continues from the new value.
+///
+/// Dragging the slider emits a [`ValueDrag`] event when the drag starts and when it ends, so
+/// that the [`ValueChange`] events in between can be treated as previews. A press on the track
+/// starts the gesture too, which ends when the pointer is released or, if the press turns into
+/// a drag, when the drag ends. Pressing Escape during the gesture cancels it and restores the
+/// value from before it started.
+///
+/// The slider is generic over its value type, which can be any [`SliderValue`], such as `f32`
+/// (the default), `f64`, `i32`, `i64` or `u32`. The [`ValueChange`] and [`ValueDrag`] events,
+/// and the `on_change` callback, carry values of the same type. Integer sliders round every
+/// emitted value to a whole number. Sliders only respond to input once a [`CoreSliderPlugin`]
+/// for their value type has been added; [`CoreWidgetsPlugin`](crate::CoreWidgetsPlugin) adds
+/// the one for `f32`.
+///
+/// The relationship between the value and the position of the thumb is determined by the
+/// `mapping` field, which defaults to a linear mapping. Keyboard and paging steps are applied to
+/// the thumb position, so that for non-linear mappings a step moves the thumb by the same
+/// distance anywhere along the slider.
+///
+/// Assistive technologies can change the value with the `Increment`, `Decrement` and `SetValue`
+/// actions. Steps requested this way behave like the arrow keys.
 #[derive(Component, Debug)]
 #[require(SliderDragState)]
-#[require(AccessibilityNode(accesskit::Node::new(Role::Slider)))]
-pub struct CoreSlider {
-    pub value: f32,
-    pub min: f32,
-    pub max: f32,
-    pub increment: f32,
+#[require(AccessibilityNode(accessible_node(
+    Role::Slider,
+    &[
+        Action::Increment,
+        Action::Decrement,
+        Action::SetValue,
+        Action::Focus
+    ]
+)))]
+pub struct CoreSlider<T: SliderValue = f32> {
+    pub value: T,
+    pub min: T,
+    pub max: T,
+    pub increment: T,
+    /// Amount to change the value by when paging.
+    pub page_increment: T,
     pub thumb_size: f32,
-    pub on_change: Option<SystemId<In<f32>>>,
+    /// Whether the slider is horizontal or vertical.
+    pub orientation: Orientation,
+    /// What happens when the user presses on the slider outside of the thumb.
+    pub track_click: TrackClick,
+    /// Mapping between the slider value and the position of the thumb.
+    pub mapping: SliderMapping,
+    /// How values are quantized before they are emitted.
+    pub snap: SliderSnap<T>,
+    pub on_change: Option<SystemId<In<ValueChange<T>>>>,
 }
 
-impl Default for CoreSlider {
+impl<T: SliderValue> Default for CoreSlider<T> {
     fn default() -> Self {
         Self {
-            value: 0.5,
-            min: 0.0,
-            max: 1.0,
-            increment: 1.0,
+            value: T::from_f64(0.5),
+            min: T::from_f64(0.0),
+            max: T::from_f64(1.0),
+            increment: T::from_f64(1.0),
+            page_increment: T::from_f64(10.0),
             thumb_size: 0.0,
+            orientation: Orientation::Horizontal,
+            track_click: TrackClick::Jump,
+            mapping: SliderMapping::Linear,
+            snap: SliderSnap::None,
             on_change: None,
         }
     }
 }
 
-impl CoreSlider {
+impl<T: SliderValue> CoreSlider<T> {
     /// Get the current value of the slider.
-    pub fn value(&self) -> f32 {
+    pub fn value(&self) -> T {
         self.value
     }
 
     /// Set the value of the slider, clamping it to the min and max values.
-    pub fn set_value(&mut self, value: f32) {
-        self.value = value.clamp(self.min, self.max);
+    pub fn set_value(&mut self, value: T) {
+        self.value = self.clamp_value(value);
     }
 
     /// Set the minimum and maximum value of the slider, clamping the current value to the new
     /// range.
-    pub fn set_range(&mut self, min: f32, max: f32) {
+    pub fn set_range(&mut self, min: T, max: T) {
         self.min = min;
         self.max = max;
-        self.value = self.value.clamp(min, max);
+        self.value = self.clamp_value(self.value);
     }
 
-    /// Compute the position of the thumb on the slider, as a value between 0 and 1.
+    /// Compute the position of the thumb on the slider, as a value between 0 and 1. For vertical
+    /// sliders, 0 is at the bottom of the slider.
     pub fn thumb_position(&self) -> f32 {
-        if self.max > self.min {
-            (self.value - self.min) / (self.max - self.min)
+        self.value_position(self.value)
+    }
+
+    /// Compute the position along the slider of an arbitrary value, as a value between 0 and 1.
+    /// This can be used to place tick marks.
+    pub fn value_position(&self, value: T) -> f32 {
+        self.position_of(value.to_f64()) as f32
+    }
+
+    /// Returns the values at which tick marks should be drawn, in ascending order. These are
+    /// the values that the slider snaps to: every increment for [`SliderSnap::Increment`], or
+    /// the detents within the slider range for [`SliderSnap::Detents`] and
+    /// [`SliderSnap::Magnetic`]. Sliders which don't snap have no ticks.
+    pub fn ticks(&self) -> Vec<T> {
+        self.tick_values().into_iter().map(T::from_f64).collect()
+    }
+
+    /// Quantize a value according to the slider's snapping mode.
+    pub fn snap_value(&self, value: T) -> T {
+        T::from_f64(self.snap(value.to_f64()))
+    }
+
+    /// Clamp a value to the range of the slider. Unlike the standard library's `clamp`, this
+    /// doesn't panic if the range is inverted.
+    fn clamp_value(&self, value: T) -> T {
+        if value < self.min {
+            self.min
+        } else if value > self.max {
+            self.max
+        } else {
+            value
+        }
+    }
+
+    /// Returns the minimum and maximum of the slider as `f64`.
+    fn bounds(&self) -> (f64, f64) {
+        (self.min.to_f64(), self.max.to_f64())
+    }
+
+    /// Compute the normalized position of a value, using the slider's mapping.
+    fn position_of(&self, value: f64) -> f64 {
+        let (min, max) = self.bounds();
+        if max > min {
+            self.mapping.to_position(value, min, max)
         } else {
             0.5
         }
     }
+
+    /// Compute the value at a normalized position, clamped to the range of the slider.
+    fn value_at(&self, position: f64) -> f64 {
+        let (min, max) = self.bounds();
+        if max > min {
+            self.mapping.to_value(position, min, max).clamp(min, max)
+        } else {
+            min
+        }
+    }
+
+    /// Compute the tick values in ascending order. See [`CoreSlider::ticks`].
+    fn tick_values(&self) -> Vec<f64> {
+        let (min, max) = self.bounds();
+        match &self.snap {
+            SliderSnap::None => Vec::new(),
+            SliderSnap::Increment => {
+                let increment = self.increment.to_f64();
+                if increment <= 0. || max < min {
+                    return Vec::new();
+                }
+                let count = ((max - min) / increment).floor() as usize + 1;
+                if count > MAX_INCREMENT_TICKS {
+                    return Vec::new();
+                }
+                (0..count)
+                    .map(|index| min + increment * index as f64)
+                    .collect()
+            }
+            SliderSnap::Detents(detents) | SliderSnap::Magnetic { detents, .. } => {
+                let mut ticks: Vec<f64> = detents
+                    .iter()
+                    .map(|detent| detent.to_f64())
+                    .filter(|detent| (min..=max).contains(detent))
+                    .collect();
+                ticks.sort_by(f64::total_cmp);
+                ticks
+            }
+        }
+    }
+
+    /// Quantize a value according to the slider's snapping mode.
+    fn snap(&self, value: f64) -> f64 {
+        match &self.snap {
+            SliderSnap::None => value,
+            SliderSnap::Increment => {
+                let increment = self.increment.to_f64();
+                if increment > 0. {
+                    let min = self.min.to_f64();
+                    min + ((value - min) / increment).round() * increment
+                } else {
+                    value
+                }
+            }
+            SliderSnap::Detents(detents) => {
+                nearest_detent(detents.iter().map(|detent| detent.to_f64()), value).unwrap_or(value)
+            }
+            SliderSnap::Magnetic { detents, radius } => {
+                match nearest_detent(detents.iter().map(|detent| detent.to_f64()), value) {
+                    Some(detent)
+                        if (self.position_of(detent) - self.position_of(value)).abs()
+                            <= f64::from(*radius) =>
+                    {
+                        detent
+                    }
+                    _ => value,
+                }
+            }
+        }
+    }
+
+    /// Compute the value after a keyboard or paging step of `amount`, taking snapping into
+    /// account. Integer sliders always move by at least one unit, if the range allows it.
+    fn step_value(&self, amount: f64) -> T {
+        let value = self.value.to_f64();
+        let new_value = T::from_f64(match &self.snap {
+            SliderSnap::Detents(detents) => {
+                return T::from_f64(
+                    next_detent(self.tick_values(), value, amount).unwrap_or_else(|| {
+                        nearest_detent(detents.iter().map(|detent| detent.to_f64()), value)
+                            .unwrap_or(value)
+                    }),
+                );
+            }
+            SliderSnap::Magnetic { .. } => self.offset_value(amount),
+            _ => self.snap(self.offset_value(amount)),
+        });
+        if !T::INTEGER || amount == 0. || new_value != self.value {
+            return new_value;
+        }
+
+        // With a non-linear mapping, a small step in thumb position can round back to the
+        // current value, which would leave the slider stuck.
+        let unit = match self.snap {
+            SliderSnap::Increment => self.increment.to_f64().max(1.),
+            _ => 1.,
+        };
+        self.clamp_value(T::from_f64(self.snap(value + unit.copysign(amount))))
+    }
+
+    /// Compute the value after moving the thumb by `amount`, where `amount` is measured in value
+    /// units for a linear slider. The result is clamped to the range of the slider.
+    fn offset_value(&self, amount: f64) -> f64 {
+        let (min, max) = self.bounds();
+        let range = max - min;
+        if range > 0. {
+            self.value_at(self.position_of(self.value.to_f64()) + amount / range)
+        } else {
+            min
+        }
+    }
+
+    /// Compute the distance that the thumb can travel, in logical pixels.
+    fn thumb_travel(&self, node: &ComputedNode) -> f32 {
+        (self.orientation.axis(node.size()) * node.inverse_scale_factor - self.thumb_size).max(1.0)
+    }
 }
 
 /// Component used to manage the state of a slider during dragging.
@@ -74,121 +307,431 @@ pub struct SliderDragState {
     /// Whether the slider is currently being dragged.
     pub dragging: bool,
     /// The value of the slider when dragging started.
-    offset: f32,
+    offset: f64,
+    /// The value that was emitted when the slider track was pressed, if any. Dragging starts
+    /// from this value, since the slider may not have been updated yet.
+    pressed_value: Option<f64>,
+    /// The value of the slider before the gesture began. This is restored if the drag is
+    /// cancelled.
+    start_value: f64,
+    /// The most recent value emitted during the drag.
+    current_value: f64,
+    /// Whether the gesture was cancelled with the Escape key. Dragging is ignored until the
+    /// slider is pressed again.
+    cancelled: bool,
 }
 
-pub(crate) fn slider_on_pointer_down(
+/// Report a new value to the slider's `on_change` callback, or as a [`ValueChange`] event.
+fn emit_slider_change<T: SliderValue>(
+    commands: &mut Commands,
+    slider_id: Entity,
+    slider: &CoreSlider<T>,
+    new_value: T,
+    input: InputSource,
+    keys: &ButtonInput<KeyCode>,
+) {
+    ValueChange {
+        value: new_value,
+        old_value: Some(slider.value),
+        source: slider_id,
+        input,
+        modifiers: Modifiers::from_keys(keys),
+    }
+    .emit(commands, slider.on_change);
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn slider_on_pointer_down<T: SliderValue>(
     trigger: Trigger<Pointer<Pressed>>,
-    q_state: Query<(), With<CoreSlider>>,
+    mut q_state: Query<(
+        &CoreSlider<T>,
+        &ComputedNode,
+        &GlobalTransform,
+        &mut SliderDragState,
+        Has<InteractionDisabled>,
+    )>,
+    keys: Res<ButtonInput<KeyCode>>,
     mut focus: ResMut<InputFocus>,
     mut focus_visible: ResMut<InputFocusVisible>,
+    mut commands: Commands,
 ) {
-    if q_state.contains(trigger.target()) {
+    if let Ok((slider, node, transform, mut drag, disabled)) = q_state.get_mut(trigger.target()) {
         // Set focus to slider and hide focus ring
         focus.0 = Some(trigger.target());
         focus_visible.0 = false;
+
+        drag.pressed_value = None;
+        drag.cancelled = false;
+        drag.start_value = slider.value.to_f64();
+        if disabled || trigger.event().button != PointerButton::Primary {
+            return;
+        }
+
+        // Position of the pointer along the slider, measured from the minimum end.
+        let local_pos =
+            pointer_local_position(node, transform, trigger.event().pointer_location.position);
+        let hit_pos = match slider.orientation {
+            Orientation::Horizontal => local_pos.x,
+            Orientation::Vertical => node.size().y * node.inverse_scale_factor - local_pos.y,
+        };
+
+        let track_click = if keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]) {
+            slider.track_click.inverted()
+        } else {
+            slider.track_click
+        };
+
+        let thumb_travel = slider.thumb_travel(node);
+        let thumb_start = slider.thumb_position() * thumb_travel;
+        if hit_pos >= thumb_start && hit_pos <= thumb_start + slider.thumb_size {
+            // Pressing on the thumb does nothing, other than allowing it to be dragged.
+            return;
+        }
+
+        let new_value = match track_click {
+            TrackClick::Jump => {
+                let position = (hit_pos - slider.thumb_size * 0.5) / thumb_travel;
+                T::from_f64(slider.snap(slider.value_at(position.into())))
+            }
+            TrackClick::Page if hit_pos < thumb_start => {
+                slider.step_value(-slider.page_increment.to_f64())
+            }
+            TrackClick::Page => slider.step_value(slider.page_increment.to_f64()),
+        };
+
+        // The press starts the gesture, so that the new value is a preview.
+        drag.pressed_value = Some(new_value.to_f64());
+        drag.current_value = new_value.to_f64();
+        commands.trigger_targets(
+            ValueDrag {
+                phase: DragPhase::Start,
+                value: slider.value,
+            },
+            trigger.target(),
+        );
+        let input = InputSource::Pointer {
+            pointer: trigger.event().pointer_id,
+            button: trigger.event().button,
+        };
+        emit_slider_change(
+            &mut commands,
+            trigger.target(),
+            slider,
+            new_value,
+            input,
+            &keys,
+        );
     }
 }
 
-pub(crate) fn slider_on_drag_start(
+pub(crate) fn slider_on_drag_start<T: SliderValue>(
     mut trigger: Trigger<Pointer<DragStart>>,
-    mut q_state: Query<(&CoreSlider, &mut SliderDragState, Has<InteractionDisabled>)>,
+    mut q_state: Query<(
+        &CoreSlider<T>,
+        &mut SliderDragState,
+        Has<InteractionDisabled>,
+    )>,
+    mut commands: Commands,
 ) {
     if let Ok((slider, mut drag, disabled)) = q_state.get_mut(trigger.target()) {
         trigger.propagate(false);
-        if !disabled {
+        if !disabled && !drag.cancelled {
             drag.dragging = true;
-            drag.offset = slider.value;
+            match drag.pressed_value.take() {
+                // The gesture already started when the track was pressed.
+                Some(pressed_value) => drag.offset = pressed_value,
+                None => {
+                    drag.start_value = slider.value.to_f64();
+                    drag.offset = drag.start_value;
+                    commands.trigger_targets(
+                        ValueDrag {
+                            phase: DragPhase::Start,
+                            value: slider.value,
+                        },
+                        trigger.target(),
+                    );
+                }
+            }
+            drag.current_value = drag.offset;
         }
     }
 }
 
-pub(crate) fn slider_on_drag(
+pub(crate) fn slider_on_drag<T: SliderValue>(
     mut trigger: Trigger<Pointer<Drag>>,
-    mut q_state: Query<(&ComputedNode, &CoreSlider, &mut SliderDragState)>,
+    mut q_state: Query<(&ComputedNode, &CoreSlider<T>, &mut SliderDragState)>,
+    keys: Res<ButtonInput<KeyCode>>,
     mut commands: Commands,
 ) {
-    if let Ok((node, slider, drag)) = q_state.get_mut(trigger.target()) {
+    if let Ok((node, slider, mut drag)) = q_state.get_mut(trigger.target()) {
         trigger.propagate(false);
         if drag.dragging {
-            let distance = trigger.event().distance;
-            // Measure node width and slider value.
-            let slider_width =
-                (node.size().x * node.inverse_scale_factor - slider.thumb_size).max(1.0);
-            let range = slider.max - slider.min;
+            // Screen coordinates increase downwards, but vertical sliders increase upwards.
+            let distance = match slider.orientation {
+                Orientation::Horizontal => trigger.event().distance.x,
+                Orientation::Vertical => -trigger.event().distance.y,
+            };
+            // Measure node length and slider value.
+            let thumb_travel = slider.thumb_travel(node);
+            let (min, max) = slider.bounds();
+            let range = max - min;
             let new_value = if range > 0. {
-                drag.offset + (distance.x * range) / slider_width
+                let start = slider.position_of(drag.offset);
+                slider.snap(slider.mapping.to_value(
+                    start + f64::from(distance / thumb_travel),
+                    min,
+                    max,
+                ))
             } else {
-                slider.min + range * 0.5
+                min + range * 0.5
             };
 
-            if let Some(on_change) = slider.on_change {
-                commands.run_system_with(on_change, new_value);
-            } else {
-                commands.trigger_targets(ValueChange(new_value), trigger.target());
-            }
+            drag.current_value = new_value;
+            let new_value = T::from_f64(new_value);
+            let input = InputSource::Pointer {
+                pointer: trigger.event().pointer_id,
+                button: trigger.event().button,
+            };
+            emit_slider_change(
+                &mut commands,
+                trigger.target(),
+                slider,
+                new_value,
+                input,
+                &keys,
+            );
         }
     }
 }
 
-pub(crate) fn slider_on_drag_end(
+pub(crate) fn slider_on_pointer_up<T: SliderValue>(
+    mut trigger: Trigger<Pointer<Released>>,
+    mut q_state: Query<&mut SliderDragState, With<CoreSlider<T>>>,
+    mut commands: Commands,
+) {
+    if let Ok(mut drag) = q_state.get_mut(trigger.target()) {
+        trigger.propagate(false);
+        // A track press which didn't turn into a drag ends the gesture when it is released.
+        if let (Some(pressed_value), false) = (drag.pressed_value.take(), drag.dragging) {
+            commands.trigger_targets(
+                ValueDrag {
+                    phase: DragPhase::Commit,
+                    value: T::from_f64(pressed_value),
+                },
+                trigger.target(),
+            );
+        }
+    }
+}
+
+pub(crate) fn slider_on_drag_end<T: SliderValue>(
     mut trigger: Trigger<Pointer<DragEnd>>,
-    mut q_state: Query<(&CoreSlider, &mut SliderDragState)>,
+    mut q_state: Query<(&CoreSlider<T>, &mut SliderDragState)>,
+    mut commands: Commands,
 ) {
     if let Ok((_slider, mut drag)) = q_state.get_mut(trigger.target()) {
         trigger.propagate(false);
         if drag.dragging {
             drag.dragging = false;
+            commands.trigger_targets(
+                ValueDrag {
+                    phase: DragPhase::Commit,
+                    value: T::from_f64(drag.current_value),
+                },
+                trigger.target(),
+            );
         }
     }
 }
 
-fn slider_on_key_input(
+fn slider_on_key_input<T: SliderValue>(
     mut trigger: Trigger<FocusedInput<KeyboardInput>>,
-    q_state: Query<(&CoreSlider, Has<InteractionDisabled>)>,
+    mut q_state: Query<(
+        &CoreSlider<T>,
+        &mut SliderDragState,
+        Has<InteractionDisabled>,
+    )>,
+    keys: Res<ButtonInput<KeyCode>>,
     mut commands: Commands,
 ) {
-    if let Ok((slider, disabled)) = q_state.get(trigger.target()) {
+    if let Ok((slider, mut drag, disabled)) = q_state.get_mut(trigger.target()) {
         let event = &trigger.event().input;
-        if !disabled && event.state == ButtonState::Pressed {
+        let in_gesture = drag.dragging || drag.pressed_value.is_some();
+        if in_gesture && event.state == ButtonState::Pressed && event.key_code == KeyCode::Escape {
+            // Cancel the gesture, and restore the original value.
+            trigger.propagate(false);
+            drag.dragging = false;
+            drag.pressed_value = None;
+            drag.cancelled = true;
+            let start_value = T::from_f64(drag.start_value);
+            emit_slider_change(
+                &mut commands,
+                trigger.target(),
+                slider,
+                start_value,
+                InputSource::Keyboard,
+                &keys,
+            );
+            commands.trigger_targets(
+                ValueDrag {
+                    phase: DragPhase::Cancel,
+                    value: start_value,
+                },
+                trigger.target(),
+            );
+        } else if !disabled && event.state == ButtonState::Pressed {
+            // Modifier keys select coarse or fine steps for the arrow keys.
+            let increment = slider.increment.to_f64();
+            let step = if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+                increment * COARSE_STEP_MULTIPLIER
+            } else if keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+                if T::INTEGER {
+                    (increment * FINE_STEP_MULTIPLIER).max(1.0)
+                } else {
+                    increment * FINE_STEP_MULTIPLIER
+                }
+            } else {
+                increment
+            };
             let new_value = match event.key_code {
-                KeyCode::ArrowLeft => (slider.value - slider.increment).max(slider.min),
-                KeyCode::ArrowRight => (slider.value + slider.increment).min(slider.max),
-                KeyCode::Home => slider.min,
-                KeyCode::End => slider.max,
+                KeyCode::ArrowLeft | KeyCode::ArrowDown => slider.step_value(-step),
+                KeyCode::ArrowRight | KeyCode::ArrowUp => slider.step_value(step),
+                KeyCode::PageDown => slider.step_value(-slider.page_increment.to_f64()),
+                KeyCode::PageUp => slider.step_value(slider.page_increment.to_f64()),
+                KeyCode::Home => slider.snap_value(slider.min),
+                KeyCode::End => slider.snap_value(slider.max),
                 _ => {
                     return;
                 }
             };
             trigger.propagate(false);
-            if let Some(on_change) = slider.on_change {
-                commands.run_system_with(on_change, new_value);
-            } else {
-                commands.trigger_targets(ValueChange(new_value), trigger.target());
-            }
+            emit_slider_change(
+                &mut commands,
+                trigger.target(),
+                slider,
+                new_value,
+                InputSource::Keyboard,
+                &keys,
+            );
         }
     }
 }
 
-fn update_slider_a11y(mut q_state: Query<(&CoreSlider, &mut AccessibilityNode)>) {
+fn slider_on_action_request<T: SliderValue>(
+    mut requests: EventReader<ActionRequest>,
+    q_state: Query<(&CoreSlider<T>, Has<InteractionDisabled>)>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+) {
+    for request in requests.read() {
+        let Some(slider_id) = action_target(request) else {
+            continue;
+        };
+        let Ok((slider, false)) = q_state.get(slider_id) else {
+            continue;
+        };
+        let new_value = match request.action {
+            Action::Increment => slider.step_value(slider.increment.to_f64()),
+            Action::Decrement => slider.step_value(-slider.increment.to_f64()),
+            Action::SetValue => match action_numeric_value(request) {
+                Some(value) => {
+                    let (min, max) = slider.bounds();
+                    T::from_f64(slider.snap(value.clamp(min, max.max(min))))
+                }
+                None => continue,
+            },
+            _ => continue,
+        };
+        emit_slider_change(
+            &mut commands,
+            slider_id,
+            slider,
+            new_value,
+            InputSource::Accessibility,
+            &keys,
+        );
+    }
+}
+
+fn update_slider_a11y<T: SliderValue>(
+    mut q_state: Query<(&CoreSlider<T>, &mut AccessibilityNode)>,
+) {
     for (slider, mut node) in q_state.iter_mut() {
-        node.set_numeric_value(slider.value.into());
-        node.set_min_numeric_value(slider.min.into());
-        node.set_max_numeric_value(slider.max.into());
-        node.set_numeric_value_step(slider.increment.into());
-        node.set_orientation(Orientation::Horizontal);
+        node.set_numeric_value(slider.value.to_f64());
+        node.set_min_numeric_value(slider.min.to_f64());
+        node.set_max_numeric_value(slider.max.to_f64());
+        node.set_numeric_value_step(slider.increment.to_f64());
+        node.set_numeric_value_jump(slider.page_increment.to_f64());
+        node.set_orientation(slider.orientation.into());
     }
 }
 
-pub struct CoreSliderPlugin;
+/// Plugin which registers the observers and systems for sliders with values of type `T`. Add one
+/// for each value type the app uses, for example `CoreSliderPlugin::<f64>::default()`.
+pub struct CoreSliderPlugin<T: SliderValue = f32>(PhantomData<T>);
 
-impl Plugin for CoreSliderPlugin {
+impl<T: SliderValue> Default for CoreSliderPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: SliderValue> Plugin for CoreSliderPlugin<T> {
     fn build(&self, app: &mut App) {
-        app.add_observer(slider_on_pointer_down)
-            .add_observer(slider_on_drag_start)
-            .add_observer(slider_on_drag_end)
-            .add_observer(slider_on_drag)
-            .add_observer(slider_on_key_input)
-            .add_systems(PostUpdate, update_slider_a11y);
+        app.add_observer(slider_on_pointer_down::<T>)
+            .add_observer(slider_on_pointer_up::<T>)
+            .add_observer(slider_on_drag_start::<T>)
+            .add_observer(slider_on_drag_end::<T>)
+            .add_observer(slider_on_drag::<T>)
+            .add_observer(slider_on_key_input::<T>)
+            .add_systems(Update, slider_on_action_request::<T>)
+            .add_systems(PostUpdate, update_slider_a11y::<T>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log_slider(value: i32) -> CoreSlider<i32> {
+        CoreSlider {
+            value,
+            min: 1,
+            max: 10000,
+            increment: 1,
+            page_increment: 10,
+            mapping: SliderMapping::Logarithmic,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn integer_log_slider_steps_by_at_least_one() {
+        let slider = log_slider(1);
+        assert_eq!(slider.step_value(1.), 2);
+        assert!(slider.step_value(10.) > 1);
+        assert!(slider.step_value(10. * COARSE_STEP_MULTIPLIER) > 1);
+        // The minimum can't be stepped below.
+        assert_eq!(slider.step_value(-1.), 1);
+
+        let slider = log_slider(500);
+        assert_eq!(slider.step_value(1.), 501);
+        assert_eq!(slider.step_value(-1.), 499);
+
+        // Larger steps which already change the value are left alone.
+        let slider = log_slider(5000);
+        assert!(slider.step_value(1.) > 5001);
+        assert_eq!(log_slider(10000).step_value(1.), 10000);
+    }
+
+    #[test]
+    fn integer_log_slider_steps_to_next_increment() {
+        let slider = CoreSlider {
+            increment: 5,
+            snap: SliderSnap::Increment,
+            ..log_slider(1)
+        };
+        assert_eq!(slider.step_value(5.), 6);
+        assert_eq!(slider.step_value(-5.), 1);
     }
 }
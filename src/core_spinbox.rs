@@ -0,0 +1,290 @@
+use accesskit::{Action, Role};
+use bevy::{
+    a11y::{AccessibilityNode, ActionRequest},
+    ecs::system::SystemId,
+    input::{keyboard::KeyboardInput, ButtonState},
+    input_focus::{FocusedInput, InputFocus, InputFocusVisible},
+    prelude::*,
+};
+
+use crate::{
+    accessibility::{accessible_node, action_numeric_value, action_target},
+    ButtonAutoRepeat, ButtonClicked, CoreButton, InputSource, InteractionDisabled, Modifiers,
+    ValueChange,
+};
+
+/// Headless spin box widget, used to edit a numeric value in discrete steps. The value can be
+/// changed with the ArrowUp / ArrowDown / PageUp / PageDown / Home / End keys while the spin box
+/// is focused, or by pressing one of its [`CoreSpinBoxButton`] children, which auto-repeat while
+/// held down. A step button steps as soon as it is pressed, rather than when it is released.
+///
+/// By default the range of the spin box is unbounded, in which case Home and End do nothing and
+/// no limits are reported to assistive technologies.
+///
+/// If the `on_change` field is `None`, the spin box will emit a [`ValueChange`] event instead.
+/// Unlike sliders, the new value is always clamped to the range of the spin box. It is the
+/// receiver's responsibility to update the spin box's value when the change is received.
+///
+/// Assistive technologies can change the value with the `Increment`, `Decrement` and `SetValue`
+/// actions.
+#[derive(Component, Debug)]
+#[require(AccessibilityNode(accessible_node(
+    Role::SpinButton,
+    &[
+        Action::Increment,
+        Action::Decrement,
+        Action::SetValue,
+        Action::Focus
+    ]
+)))]
+pub struct CoreSpinBox {
+    pub value: f32,
+    /// Minimum value, or `f32::NEG_INFINITY` if there is no minimum.
+    pub min: f32,
+    /// Maximum value, or `f32::INFINITY` if there is no maximum.
+    pub max: f32,
+    /// Amount to change the value by when a step button or arrow key is pressed.
+    pub increment: f32,
+    /// Amount to change the value by when the PageUp or PageDown key is pressed.
+    pub page_increment: f32,
+    pub on_change: Option<SystemId<In<ValueChange<f32>>>>,
+}
+
+impl Default for CoreSpinBox {
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            min: f32::NEG_INFINITY,
+            max: f32::INFINITY,
+            increment: 1.0,
+            page_increment: 10.0,
+            on_change: None,
+        }
+    }
+}
+
+impl CoreSpinBox {
+    /// Get the current value of the spin box.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Set the value of the spin box, clamping it to the min and max values.
+    pub fn set_value(&mut self, value: f32) {
+        self.value = self.clamp_value(value);
+    }
+
+    /// Set the minimum and maximum value of the spin box, clamping the current value to the new
+    /// range.
+    pub fn set_range(&mut self, min: f32, max: f32) {
+        self.min = min;
+        self.max = max;
+        self.value = self.clamp_value(self.value);
+    }
+
+    /// Compute the value after moving by `amount`, clamped to the range of the spin box.
+    fn offset_value(&self, amount: f32) -> f32 {
+        self.clamp_value(self.value + amount)
+    }
+
+    /// Clamp a value to the range of the spin box. Unlike the standard library's `clamp`, this
+    /// doesn't panic if the range is inverted or a bound is NaN.
+    fn clamp_value(&self, value: f32) -> f32 {
+        if value < self.min {
+            self.min
+        } else if value > self.max {
+            self.max
+        } else {
+            value
+        }
+    }
+}
+
+/// Component for the step buttons of a spin box. This should be placed on an entity which is
+/// a descendant of the [`CoreSpinBox`]; it adds a [`CoreButton`] with a [`ButtonAutoRepeat`]
+/// component to the entity, which is used to handle the pointer and keyboard interactions. Each
+/// click of the button, including the first one when it is pressed, is one step; the repeat
+/// timing can be adjusted by replacing the `ButtonAutoRepeat` component.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[require(CoreButton, ButtonAutoRepeat)]
+pub enum CoreSpinBoxButton {
+    /// Button which increases the value of the spin box.
+    Increment,
+    /// Button which decreases the value of the spin box.
+    Decrement,
+}
+
+impl CoreSpinBoxButton {
+    fn sign(&self) -> f32 {
+        match self {
+            CoreSpinBoxButton::Increment => 1.0,
+            CoreSpinBoxButton::Decrement => -1.0,
+        }
+    }
+}
+
+fn emit_spinbox_change(
+    commands: &mut Commands,
+    spinbox_id: Entity,
+    spinbox: &CoreSpinBox,
+    new_value: f32,
+    input: InputSource,
+    modifiers: Modifiers,
+) {
+    ValueChange {
+        value: new_value,
+        old_value: Some(spinbox.value),
+        source: spinbox_id,
+        input,
+        modifiers,
+    }
+    .emit(commands, spinbox.on_change);
+}
+
+fn spinbox_on_pointer_down(
+    trigger: Trigger<Pointer<Pressed>>,
+    q_state: Query<(), With<CoreSpinBox>>,
+    mut focus: ResMut<InputFocus>,
+    mut focus_visible: ResMut<InputFocusVisible>,
+) {
+    if q_state.contains(trigger.target()) {
+        // Set focus to spin box and hide focus ring
+        focus.0 = Some(trigger.target());
+        focus_visible.0 = false;
+    }
+}
+
+fn spinbox_on_key_input(
+    mut trigger: Trigger<FocusedInput<KeyboardInput>>,
+    q_state: Query<(&CoreSpinBox, Has<InteractionDisabled>)>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+) {
+    if let Ok((spinbox, disabled)) = q_state.get(trigger.target()) {
+        let event = &trigger.event().input;
+        if !disabled && event.state == ButtonState::Pressed {
+            let new_value = match event.key_code {
+                KeyCode::ArrowUp => spinbox.offset_value(spinbox.increment),
+                KeyCode::ArrowDown => spinbox.offset_value(-spinbox.increment),
+                KeyCode::PageUp => spinbox.offset_value(spinbox.page_increment),
+                KeyCode::PageDown => spinbox.offset_value(-spinbox.page_increment),
+                KeyCode::Home if spinbox.min.is_finite() => spinbox.min,
+                KeyCode::End if spinbox.max.is_finite() => spinbox.max,
+                _ => {
+                    return;
+                }
+            };
+            trigger.propagate(false);
+            emit_spinbox_change(
+                &mut commands,
+                trigger.target(),
+                spinbox,
+                new_value,
+                InputSource::Keyboard,
+                Modifiers::from_keys(&keys),
+            );
+        }
+    }
+}
+
+fn spinbox_on_button_click(
+    mut trigger: Trigger<ButtonClicked>,
+    q_button: Query<&CoreSpinBoxButton>,
+    q_parent: Query<&ChildOf>,
+    q_spinbox: Query<(&CoreSpinBox, Has<InteractionDisabled>)>,
+    mut commands: Commands,
+) {
+    let button_id = trigger.target();
+    let Ok(button) = q_button.get(button_id) else {
+        return;
+    };
+
+    // Find the spin box which owns this button.
+    let Some(spinbox_id) = q_parent
+        .iter_ancestors(button_id)
+        .find(|ancestor| q_spinbox.contains(*ancestor))
+    else {
+        warn!("Spin box button clicked without a CoreSpinBox ancestor");
+        return;
+    };
+
+    // The button auto-repeats, so each click is one step.
+    trigger.propagate(false);
+    let (spinbox, disabled) = q_spinbox.get(spinbox_id).unwrap();
+    if !disabled {
+        let new_value = spinbox.offset_value(button.sign() * spinbox.increment);
+        let click = trigger.event();
+        emit_spinbox_change(
+            &mut commands,
+            spinbox_id,
+            spinbox,
+            new_value,
+            click.input,
+            click.modifiers,
+        );
+    }
+}
+
+fn spinbox_on_action_request(
+    mut requests: EventReader<ActionRequest>,
+    q_state: Query<(&CoreSpinBox, Has<InteractionDisabled>)>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+) {
+    for request in requests.read() {
+        let Some(spinbox_id) = action_target(request) else {
+            continue;
+        };
+        let Ok((spinbox, false)) = q_state.get(spinbox_id) else {
+            continue;
+        };
+        let new_value = match request.action {
+            Action::Increment => spinbox.offset_value(spinbox.increment),
+            Action::Decrement => spinbox.offset_value(-spinbox.increment),
+            Action::SetValue => match action_numeric_value(request) {
+                Some(value) => spinbox.clamp_value(value as f32),
+                None => continue,
+            },
+            _ => continue,
+        };
+        emit_spinbox_change(
+            &mut commands,
+            spinbox_id,
+            spinbox,
+            new_value,
+            InputSource::Accessibility,
+            Modifiers::from_keys(&keys),
+        );
+    }
+}
+
+fn update_spinbox_a11y(mut q_state: Query<(&CoreSpinBox, &mut AccessibilityNode)>) {
+    for (spinbox, mut node) in q_state.iter_mut() {
+        node.set_numeric_value(spinbox.value.into());
+        // Unbounded ends of the range aren't reported.
+        if spinbox.min.is_finite() {
+            node.set_min_numeric_value(spinbox.min.into());
+        } else {
+            node.clear_min_numeric_value();
+        }
+        if spinbox.max.is_finite() {
+            node.set_max_numeric_value(spinbox.max.into());
+        } else {
+            node.clear_max_numeric_value();
+        }
+        node.set_numeric_value_step(spinbox.increment.into());
+        node.set_numeric_value_jump(spinbox.page_increment.into());
+    }
+}
+
+pub struct CoreSpinBoxPlugin;
+
+impl Plugin for CoreSpinBoxPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(spinbox_on_pointer_down)
+            .add_observer(spinbox_on_key_input)
+            .add_observer(spinbox_on_button_click)
+            .add_systems(Update, spinbox_on_action_request)
+            .add_systems(PostUpdate, update_spinbox_a11y);
+    }
+}
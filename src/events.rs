@@ -1,9 +1,78 @@
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemId, picking::pointer::PointerId, prelude::*};
+
+/// The kind of input which caused a widget to emit an event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputSource {
+    /// A pointer, such as the mouse or a touch, along with the pointer button which was used.
+    Pointer {
+        pointer: PointerId,
+        button: PointerButton,
+    },
+    /// A key press while the widget was focused.
+    Keyboard,
+    /// An action requested by an assistive technology.
+    Accessibility,
+}
+
+impl InputSource {
+    /// Input from a press of the primary button of the given pointer.
+    pub(crate) fn primary(pointer: PointerId) -> Self {
+        InputSource::Pointer {
+            pointer,
+            button: PointerButton::Primary,
+        }
+    }
+}
+
+/// The modifier keys which were held down when a widget emitted an event.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+    /// The Command key on macOS, or the Windows key on other platforms.
+    pub super_key: bool,
+}
+
+impl Modifiers {
+    /// Read the state of the modifier keys.
+    pub fn from_keys(keys: &ButtonInput<KeyCode>) -> Self {
+        Self {
+            shift: keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+            control: keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]),
+            alt: keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+            super_key: keys.any_pressed([KeyCode::SuperLeft, KeyCode::SuperRight]),
+        }
+    }
+
+    /// Whether the platform's usual modifier for shortcuts and multiple selection is held down:
+    /// Command on macOS, and Control elsewhere.
+    pub fn command(&self) -> bool {
+        if cfg!(target_os = "macos") {
+            self.super_key
+        } else {
+            self.control
+        }
+    }
+}
 
 /// An event that indicates a change in value of a property. This is used by sliders, spinners
-/// and other widgets that edit a value.
+/// and other widgets that edit a value. Widgets with an `on_change` callback pass this to the
+/// callback instead of emitting it.
 #[derive(Clone, Debug)]
-pub struct ValueChange<T>(pub T);
+pub struct ValueChange<T> {
+    /// The new value.
+    pub value: T,
+    /// The value before the change. This is `None` if the widget had no value, such as a radio
+    /// group with no checked button.
+    pub old_value: Option<T>,
+    /// The widget whose value changed.
+    pub source: Entity,
+    /// The input which caused the change.
+    pub input: InputSource,
+    /// The modifier keys which were held down when the change was made.
+    pub modifiers: Modifiers,
+}
 
 impl<T: Send + Sync + 'static> Event for ValueChange<T> {
     type Traversal = &'static ChildOf;
@@ -11,14 +80,113 @@ impl<T: Send + Sync + 'static> Event for ValueChange<T> {
     const AUTO_PROPAGATE: bool = true;
 }
 
+impl<T: Send + Sync + 'static> ValueChange<T> {
+    /// Pass the change to the widget's `on_change` callback, or emit it as an event targeting
+    /// the widget if there is no callback.
+    pub(crate) fn emit(
+        self,
+        commands: &mut Commands,
+        on_change: Option<SystemId<In<ValueChange<T>>>>,
+    ) {
+        if let Some(on_change) = on_change {
+            commands.run_system_with(on_change, self);
+        } else {
+            let source = self.source;
+            commands.trigger_targets(self, source);
+        }
+    }
+}
+
 /// An event which is emitted when a button is clicked. This is different from the
 /// [`Pointer<Click>`] event, because it's also emitted when the button is focused and the `Enter`
-/// or `Space` key is pressed.
+/// or `Space` key is pressed. Buttons with an `on_click` callback pass this to the callback
+/// instead of emitting it.
 #[derive(Clone, Debug)]
-pub struct ButtonClicked;
+pub struct ButtonClicked {
+    /// The button which was clicked.
+    pub source: Entity,
+    /// The input which clicked the button.
+    pub input: InputSource,
+    /// The modifier keys which were held down when the button was clicked.
+    pub modifiers: Modifiers,
+    /// The number of clicks in quick succession. This is 2 for the second click of a double
+    /// click on a button with a [`ButtonDoubleClick`](crate::ButtonDoubleClick) component, and 1
+    /// otherwise.
+    pub click_count: u32,
+}
 
 impl Event for ButtonClicked {
     type Traversal = &'static ChildOf;
 
     const AUTO_PROPAGATE: bool = true;
 }
+
+impl ButtonClicked {
+    /// A single click on `source`.
+    pub(crate) fn new(source: Entity, input: InputSource, modifiers: Modifiers) -> Self {
+        Self {
+            source,
+            input,
+            modifiers,
+            click_count: 1,
+        }
+    }
+}
+
+/// An event which is emitted when a button with a [`ButtonLongPress`](crate::ButtonLongPress)
+/// component is held down for long enough, and has no `on_long_press` callback.
+#[derive(Clone, Debug)]
+pub struct ButtonLongPressed;
+
+impl Event for ButtonLongPressed {
+    type Traversal = &'static ChildOf;
+
+    const AUTO_PROPAGATE: bool = true;
+}
+
+/// An event which is emitted when a button with a [`ButtonDoubleClick`](crate::ButtonDoubleClick)
+/// component is clicked twice in quick succession, and has no `on_double_click` callback. Both
+/// clicks are also reported as ordinary clicks.
+#[derive(Clone, Debug)]
+pub struct ButtonDoubleClicked;
+
+impl Event for ButtonDoubleClicked {
+    type Traversal = &'static ChildOf;
+
+    const AUTO_PROPAGATE: bool = true;
+}
+
+/// The stage of a drag gesture which is reported by a [`ValueDrag`] event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DragPhase {
+    /// The drag has started. The value is the value before the gesture began.
+    Start,
+    /// The drag has finished normally. The value is the final value of the gesture.
+    Commit,
+    /// The gesture was cancelled by pressing the Escape key. The value is the value from before the
+    /// gesture began, which has been restored.
+    Cancel,
+}
+
+/// An event which is emitted at the start and end of a drag gesture on a slider or scrollbar.
+/// Any [`ValueChange`] events which occur between the `Start` and the `Commit` or `Cancel`
+/// phases are live previews, which is useful for undo systems that should only record the
+/// committed value.
+///
+/// Pressing the track of a slider or scrollbar, outside of the thumb, is part of the gesture as
+/// well: `Start` is emitted before the press changes the value, and `Commit` when the pointer
+/// is released or, if the press turns into a drag, when the drag ends.
+///
+/// Unlike [`ValueChange`], this event is emitted even when the widget has an `on_change`
+/// callback.
+#[derive(Clone, Debug)]
+pub struct ValueDrag<T> {
+    pub phase: DragPhase,
+    pub value: T,
+}
+
+impl<T: Send + Sync + 'static> Event for ValueDrag<T> {
+    type Traversal = &'static ChildOf;
+
+    const AUTO_PROPAGATE: bool = true;
+}
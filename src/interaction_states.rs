@@ -37,6 +37,13 @@ fn on_remove_disabled(mut world: DeferredWorld, context: HookContext) {
 #[derive(Component, Default, Debug)]
 pub struct ButtonPressed(pub bool);
 
+/// Component that indicates whether a pressed button is "armed", meaning that it will be clicked
+/// if it is released now. This is true while the button is pressed and the pointer is over it,
+/// and false while the pointer has been dragged off the button, so that styles can show that
+/// releasing the pointer will cancel the click.
+#[derive(Component, Default, Debug)]
+pub struct ButtonArmed(pub bool);
+
 /// Component that indicates whether a checkbox or radio button is in a checked state.
 #[derive(Component, Default, Debug)]
 #[component(immutable, on_add = on_add_checked, on_replace = on_add_checked)]
@@ -52,3 +59,18 @@ fn on_add_checked(mut world: DeferredWorld, context: HookContext) {
         false => accesskit::Toggled::False,
     });
 }
+
+/// Component that indicates whether an expandable widget, such as a disclosure toggle, is in an
+/// expanded state.
+#[derive(Component, Default, Debug)]
+#[component(immutable, on_insert = on_insert_expanded)]
+pub struct Expanded(pub bool);
+
+// Hook to set the a11y "expanded" state whenever the component is inserted or replaced.
+fn on_insert_expanded(mut world: DeferredWorld, context: HookContext) {
+    let mut entt = world.entity_mut(context.entity);
+    let expanded = entt.get::<Expanded>().unwrap().0;
+    if let Some(mut accessibility) = entt.get_mut::<AccessibilityNode>() {
+        accessibility.set_expanded(expanded);
+    }
+}
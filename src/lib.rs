@@ -1,26 +1,63 @@
-use bevy::app::{App, Plugin, Update};
+use bevy::{
+    a11y::ActionRequest,
+    app::{App, Plugin, Update},
+};
+mod accessibility;
 mod core_barrier;
 mod core_button;
 mod core_checkbox;
+mod core_disclosure_toggle;
 mod core_radio;
 mod core_radio_group;
+mod core_scroll_area;
 mod core_scrollbar;
 mod core_slider;
+mod core_spinbox;
 mod cursor;
 mod events;
 pub mod hover;
 mod interaction_states;
+mod orientation;
+mod repeat;
+mod scroll_into_view;
+mod scroll_link;
+mod scrollbar_visibility;
+mod slider_mapping;
+mod slider_snap;
+mod slider_value;
+mod track;
 
 pub use core_barrier::{CoreBarrier, CoreBarrierPlugin};
-pub use core_button::{CoreButton, CoreButtonPlugin};
+pub use core_button::{
+    ButtonAutoRepeat, ButtonDoubleClick, ButtonGestureState, ButtonKeyState, ButtonLongPress,
+    ButtonRepeatState, CoreButton, CoreButtonPlugin,
+};
 pub use core_checkbox::{CoreCheckbox, CoreCheckboxPlugin};
+pub use core_disclosure_toggle::{CoreDisclosureToggle, CoreDisclosureTogglePlugin};
 pub use core_radio::{CoreRadio, CoreRadioPlugin};
 pub use core_radio_group::{CoreRadioGroup, CoreRadioGroupPlugin};
-pub use core_scrollbar::{CoreScrollbar, CoreScrollbarPlugin, CoreScrollbarThumb, Orientation};
+pub use core_scroll_area::{CoreScrollArea, CoreScrollAreaPlugin, ScrollAnimation, SmoothScroll};
+pub use core_scrollbar::{
+    CoreScrollbar, CoreScrollbarPlugin, CoreScrollbarStepButton, CoreScrollbarThumb, StepDirection,
+};
 pub use core_slider::{CoreSlider, CoreSliderPlugin, SliderDragState};
+pub use core_spinbox::{CoreSpinBox, CoreSpinBoxButton, CoreSpinBoxPlugin};
 pub use cursor::CursorIconPlugin;
-pub use events::{ButtonClicked, ValueChange};
-pub use interaction_states::{ButtonPressed, Checked, InteractionDisabled};
+pub use events::{
+    ButtonClicked, ButtonDoubleClicked, ButtonLongPressed, DragPhase, InputSource, Modifiers,
+    ValueChange, ValueDrag,
+};
+pub use interaction_states::{ButtonArmed, ButtonPressed, Checked, Expanded, InteractionDisabled};
+pub use orientation::Orientation;
+pub use scroll_into_view::{
+    scroll_into_view, FocusScrollSettings, ScrollAlign, ScrollIntoView, ScrollIntoViewOptions,
+};
+pub use scroll_link::{ScrollLink, ScrollLinkPlugin};
+pub use scrollbar_visibility::{ScrollbarAutoHide, ScrollbarVisibility};
+pub use slider_mapping::{SliderMapping, ValueMapping};
+pub use slider_snap::SliderSnap;
+pub use slider_value::SliderValue;
+pub use track::TrackClick;
 
 pub struct CoreWidgetsPlugin;
 
@@ -30,12 +67,24 @@ impl Plugin for CoreWidgetsPlugin {
             CoreBarrierPlugin,
             CoreButtonPlugin,
             CoreCheckboxPlugin,
+            CoreDisclosureTogglePlugin,
             CoreRadioPlugin,
             CoreRadioGroupPlugin,
+            CoreScrollAreaPlugin,
             CoreScrollbarPlugin,
-            CoreSliderPlugin,
+            CoreSliderPlugin::<f32>::default(),
+            CoreSpinBoxPlugin,
             CursorIconPlugin,
         ))
-        .add_systems(Update, hover::update_hover_states);
+        // The widget plugins read accessibility action requests. Bevy's `AccessibilityPlugin`
+        // registers this event too, but apps don't need to include it.
+        .add_event::<ActionRequest>()
+        .add_systems(
+            Update,
+            (
+                hover::update_hover_states,
+                accessibility::focus_on_action_request,
+            ),
+        );
     }
 }
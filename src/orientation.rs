@@ -0,0 +1,28 @@
+use bevy::math::Vec2;
+
+/// The direction along which a widget, such as a slider or scrollbar, is laid out.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Horizontal,
+    #[default]
+    Vertical,
+}
+
+impl Orientation {
+    /// Return the component of a vector which lies along this orientation.
+    pub(crate) fn axis(self, value: Vec2) -> f32 {
+        match self {
+            Orientation::Horizontal => value.x,
+            Orientation::Vertical => value.y,
+        }
+    }
+}
+
+impl From<Orientation> for accesskit::Orientation {
+    fn from(orientation: Orientation) -> Self {
+        match orientation {
+            Orientation::Horizontal => accesskit::Orientation::Horizontal,
+            Orientation::Vertical => accesskit::Orientation::Vertical,
+        }
+    }
+}
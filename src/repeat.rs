@@ -0,0 +1,34 @@
+/// Delay, in seconds, before a held control starts to auto-repeat.
+pub(crate) const REPEAT_DELAY: f32 = 0.5;
+
+/// Interval, in seconds, between successive auto-repeats once repeating has started.
+pub(crate) const REPEAT_INTERVAL: f32 = 0.05;
+
+/// Tracks elapsed time for press-and-hold auto-repeat, such as on auto-repeating buttons.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct RepeatTimer {
+    /// Time since the press started, in seconds.
+    elapsed: f32,
+    /// Number of repeats that have fired since the press started.
+    count: u32,
+}
+
+impl RepeatTimer {
+    /// Restart the timer at the beginning of a new press.
+    pub(crate) fn reset(&mut self) {
+        self.elapsed = 0.;
+        self.count = 0;
+    }
+
+    /// Advance the timer by `delta` seconds, and return the number of repeats which became due.
+    pub(crate) fn tick(&mut self, delta: f32, delay: f32, interval: f32) -> u32 {
+        self.elapsed += delta;
+        if self.elapsed < delay {
+            return 0;
+        }
+        let due = 1 + ((self.elapsed - delay) / interval.max(0.001)) as u32;
+        let fired = due.saturating_sub(self.count);
+        self.count = due;
+        fired
+    }
+}
@@ -0,0 +1,203 @@
+use bevy::{
+    input_focus::{InputFocus, InputFocusVisible},
+    math::Rect,
+    prelude::*,
+    ui::OverflowAxis,
+};
+
+use crate::core_scroll_area::{max_scroll_offset, ScrollAnimation};
+
+/// Where an entity should end up within its scrolling container when it is scrolled into view.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScrollAlign {
+    /// Scroll as little as possible. Entities which are already visible don't move.
+    #[default]
+    Nearest,
+    /// Align the start (top or left edge) of the entity with the start of the visible area.
+    Start,
+    /// Center the entity within the visible area.
+    Center,
+}
+
+/// Options which control how an entity is scrolled into view.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ScrollIntoViewOptions {
+    /// Where the entity should end up within the visible area.
+    pub align: ScrollAlign,
+    /// Extra space to leave between the entity and the edges of the visible area, in logical
+    /// pixels.
+    pub margin: f32,
+}
+
+/// Resource which controls whether scrolling containers automatically scroll to reveal the
+/// focused entity whenever [`InputFocus`] changes. This only happens for keyboard focus, that is
+/// while [`InputFocusVisible`] is true; clicking a partly visible widget doesn't scroll it.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct FocusScrollSettings {
+    /// Whether focus changes scroll the newly focused entity into view.
+    pub enabled: bool,
+    /// How the focused entity is scrolled into view.
+    pub options: ScrollIntoViewOptions,
+}
+
+impl Default for FocusScrollSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            options: ScrollIntoViewOptions::default(),
+        }
+    }
+}
+
+/// Command which scrolls every scrolling container that encloses an entity, innermost first, so
+/// that the entity becomes visible. Only axes whose overflow is set to
+/// [`OverflowAxis::Scroll`] are scrolled. Containers with a
+/// [`SmoothScroll`](crate::SmoothScroll) component animate to the new position.
+///
+/// The calculation uses the most recent layout, so entities which were spawned during the
+/// current frame can't be scrolled into view until after the next layout pass.
+#[derive(Debug, Clone, Copy)]
+pub struct ScrollIntoView {
+    /// The entity to reveal.
+    pub entity: Entity,
+    /// How the entity is scrolled into view.
+    pub options: ScrollIntoViewOptions,
+}
+
+impl ScrollIntoView {
+    /// Set where the entity should end up within the visible area.
+    pub fn align(mut self, align: ScrollAlign) -> Self {
+        self.options.align = align;
+        self
+    }
+
+    /// Set the space to leave between the entity and the edges of the visible area.
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.options.margin = margin;
+        self
+    }
+}
+
+impl Command for ScrollIntoView {
+    fn apply(self, world: &mut World) {
+        if let Err(err) =
+            world.run_system_cached_with(scroll_entity_into_view, (self.entity, self.options))
+        {
+            warn!("Failed to scroll entity into view: {err}");
+        }
+    }
+}
+
+/// Create a [`ScrollIntoView`] command for the given entity, using the default options. For
+/// example: `commands.queue(scroll_into_view(entity).align(ScrollAlign::Center))`.
+pub fn scroll_into_view(entity: Entity) -> ScrollIntoView {
+    ScrollIntoView {
+        entity,
+        options: ScrollIntoViewOptions::default(),
+    }
+}
+
+/// Returns the rectangle occupied by a node, in logical pixels.
+fn node_rect(node: &ComputedNode, transform: &GlobalTransform) -> Rect {
+    Rect::from_center_size(
+        transform.translation().truncate() * node.inverse_scale_factor,
+        node.size() * node.inverse_scale_factor,
+    )
+}
+
+/// Compute how far a container must scroll along one axis so that the span from `start` to
+/// `end` is visible within the span from `view_start` to `view_end`.
+fn scroll_delta(
+    start: f32,
+    end: f32,
+    view_start: f32,
+    view_end: f32,
+    options: &ScrollIntoViewOptions,
+) -> f32 {
+    let start = start - options.margin;
+    let end = end + options.margin;
+    match options.align {
+        ScrollAlign::Start => start - view_start,
+        ScrollAlign::Center => (start + end - view_start - view_end) * 0.5,
+        // Larger than the visible area, so show as much of the start as possible.
+        ScrollAlign::Nearest if end - start > view_end - view_start => start - view_start,
+        ScrollAlign::Nearest if start < view_start => start - view_start,
+        ScrollAlign::Nearest if end > view_end => end - view_end,
+        ScrollAlign::Nearest => 0.,
+    }
+}
+
+fn scroll_entity_into_view(
+    In((entity, options)): In<(Entity, ScrollIntoViewOptions)>,
+    q_parent: Query<&ChildOf>,
+    q_layout: Query<(&ComputedNode, &GlobalTransform)>,
+    mut q_container: Query<(&Node, &mut ScrollPosition, Option<&mut ScrollAnimation>)>,
+) {
+    let Ok((node, transform)) = q_layout.get(entity) else {
+        return;
+    };
+    let mut target = node_rect(node, transform);
+
+    for ancestor in q_parent.iter_ancestors(entity) {
+        let (Ok((style, mut scroll_pos, anim)), Ok((node, transform))) =
+            (q_container.get_mut(ancestor), q_layout.get(ancestor))
+        else {
+            continue;
+        };
+
+        // The visible area is inside the container's border.
+        let border = node.border();
+        let mut view = node_rect(node, transform);
+        view.min += Vec2::new(border.left, border.top) * node.inverse_scale_factor;
+        view.max -= Vec2::new(border.right, border.bottom) * node.inverse_scale_factor;
+
+        let mut delta = Vec2::ZERO;
+        if style.overflow.x == OverflowAxis::Scroll {
+            delta.x = scroll_delta(target.min.x, target.max.x, view.min.x, view.max.x, &options);
+        }
+        if style.overflow.y == OverflowAxis::Scroll {
+            delta.y = scroll_delta(target.min.y, target.max.y, view.min.y, view.max.y, &options);
+        }
+
+        let offset = Vec2::new(scroll_pos.offset_x, scroll_pos.offset_y);
+        let new_offset = (offset + delta).clamp(Vec2::ZERO, max_scroll_offset(node));
+        if new_offset != offset {
+            match anim {
+                Some(mut anim) => anim.ease_to(new_offset),
+                None => {
+                    scroll_pos.offset_x = new_offset.x;
+                    scroll_pos.offset_y = new_offset.y;
+                }
+            }
+        }
+
+        // Enclosing containers see the entity at the position it will have after scrolling.
+        let applied = new_offset - offset;
+        target.min -= applied;
+        target.max -= applied;
+    }
+}
+
+pub(crate) fn scroll_focus_into_view(
+    focus: Option<Res<InputFocus>>,
+    focus_visible: Option<Res<InputFocusVisible>>,
+    settings: Res<FocusScrollSettings>,
+    mut last_focus: Local<Option<Entity>>,
+    mut commands: Commands,
+) {
+    let Some(focus) = focus else {
+        return;
+    };
+    if !focus.is_changed() || focus.0 == *last_focus {
+        return;
+    }
+    *last_focus = focus.0;
+    // Only keyboard navigation scrolls; pointer presses focus widgets which are already visible.
+    let keyboard = focus_visible.is_some_and(|visible| visible.0);
+    if let (true, true, Some(focus_id)) = (settings.enabled, keyboard, focus.0) {
+        commands.queue(ScrollIntoView {
+            entity: focus_id,
+            options: settings.options,
+        });
+    }
+}
@@ -0,0 +1,140 @@
+use bevy::{platform::collections::HashMap, prelude::*, ui::UiSystem};
+
+use crate::{
+    core_scroll_area::{max_scroll_offset, ScrollAnimation},
+    core_scrollbar::{scroll_offset, set_scroll_offset, update_scrollbar_thumb},
+    scroll_into_view::scroll_focus_into_view,
+    Orientation,
+};
+
+/// Component which links the [`ScrollPosition`] of a scrolling container to other containers
+/// along one or both axes. Whenever any member of a link group is scrolled along the group's
+/// axis, by any means, the other members follow. This is useful for tables with frozen headers,
+/// side-by-side diff views and timelines.
+///
+/// A group is identified by an entity, which can be any entity, such as a common ancestor of the
+/// linked containers. A container can belong to one group for each axis; for example, the body
+/// of a table with a frozen header row and column is linked horizontally with the header row and
+/// vertically with the header column.
+///
+/// Since the members stay synchronized, a single [`CoreScrollbar`](crate::CoreScrollbar) which
+/// targets any one of them scrolls them all.
+///
+/// Linking is handled by the [`ScrollLinkPlugin`], which is added by both
+/// [`CoreScrollAreaPlugin`](crate::CoreScrollAreaPlugin) and
+/// [`CoreScrollbarPlugin`](crate::CoreScrollbarPlugin).
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct ScrollLink {
+    /// The group whose members share the horizontal scroll offset, if any.
+    pub horizontal: Option<Entity>,
+    /// The group whose members share the vertical scroll offset, if any.
+    pub vertical: Option<Entity>,
+}
+
+impl ScrollLink {
+    /// Link the horizontal scroll offset with the other members of `group`.
+    pub fn horizontal(group: Entity) -> Self {
+        Self {
+            horizontal: Some(group),
+            vertical: None,
+        }
+    }
+
+    /// Link the vertical scroll offset with the other members of `group`.
+    pub fn vertical(group: Entity) -> Self {
+        Self {
+            horizontal: None,
+            vertical: Some(group),
+        }
+    }
+
+    /// The groups which this container belongs to, along with the axis each one shares.
+    fn groups(&self) -> impl Iterator<Item = (Orientation, Entity)> {
+        [
+            (Orientation::Horizontal, self.horizontal),
+            (Orientation::Vertical, self.vertical),
+        ]
+        .into_iter()
+        .filter_map(|(orientation, group)| Some((orientation, group?)))
+    }
+}
+
+/// Offsets which linked containers were synchronized to on the previous frame.
+#[derive(Default)]
+pub(crate) struct LinkedOffsets {
+    /// Shared offset of each group, keyed by group and axis.
+    groups: HashMap<(Entity, Orientation), f32>,
+    /// Offset of each member, keyed by member and axis. This differs from the group's offset
+    /// when the member can't scroll as far as the others.
+    members: HashMap<(Entity, Orientation), f32>,
+}
+
+fn sync_linked_scroll_positions(
+    mut q_linked: Query<(
+        Entity,
+        &ScrollLink,
+        &ComputedNode,
+        &mut ScrollPosition,
+        Option<&mut ScrollAnimation>,
+    )>,
+    mut synced: Local<LinkedOffsets>,
+) {
+    // Work out the shared offset for each group. A member which was scrolled since the last
+    // update determines the new offset. Members which are new to an existing group follow it.
+    let mut groups = HashMap::<(Entity, Orientation), f32>::default();
+    for (member, link, _, scroll_pos, _) in q_linked.iter() {
+        for (orientation, group) in link.groups() {
+            let key = (group, orientation);
+            let last = synced.groups.get(&key).copied();
+            let offset = groups.entry(key).or_insert(last.unwrap_or(0.));
+            let member_offset = scroll_offset(orientation, scroll_pos);
+            let scrolled = match synced.members.get(&(member, orientation)) {
+                Some(previous) => member_offset != *previous,
+                None => last.is_none() && member_offset != 0.,
+            };
+            if scrolled {
+                *offset = member_offset;
+            }
+        }
+    }
+
+    // Move the members to the shared offset, as far as each of them can scroll.
+    let mut members = HashMap::<(Entity, Orientation), f32>::default();
+    for (member, link, node, mut scroll_pos, mut anim) in q_linked.iter_mut() {
+        if node.size() == Vec2::ZERO {
+            // Not laid out yet, so the scrolling range is unknown.
+            continue;
+        }
+        let max_offset = max_scroll_offset(node);
+        for (orientation, group) in link.groups() {
+            let offset = groups[&(group, orientation)].clamp(0., orientation.axis(max_offset));
+            if scroll_offset(orientation, &scroll_pos) != offset {
+                // The member is following another one, so its own animation is out of date.
+                if let Some(anim) = anim.as_deref_mut() {
+                    anim.stop();
+                }
+                set_scroll_offset(orientation, &mut scroll_pos, offset);
+            }
+            members.insert((member, orientation), offset);
+        }
+    }
+
+    // Groups and members which no longer exist are dropped here.
+    *synced = LinkedOffsets { groups, members };
+}
+
+/// Plugin which keeps the members of [`ScrollLink`] groups synchronized. This is added
+/// automatically by the scroll area and scrollbar plugins.
+pub struct ScrollLinkPlugin;
+
+impl Plugin for ScrollLinkPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            sync_linked_scroll_positions
+                .after(scroll_focus_into_view)
+                .before(update_scrollbar_thumb)
+                .before(UiSystem::Layout),
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+
+use crate::{
+    core_scrollbar::{scroll_offset, ScrollbarDragState},
+    hover::Hovering,
+    ButtonPressed, CoreScrollbar, CoreScrollbarStepButton,
+};
+
+/// Component which makes a [`CoreScrollbar`] hide itself when it isn't in use, like the overlay
+/// scrollbars on macOS and mobile platforms. The scrollbar is hidden when the content fits within
+/// the visible area, shown while the content is scrolling or while the scrollbar is hovered or
+/// pressed, and faded out once it has been idle for `idle_timeout` seconds.
+///
+/// The scrollbar's state is reported by the [`ScrollbarVisibility`] component. The widget
+/// doesn't change the scrollbar's appearance itself; styling systems should read the visibility
+/// and apply it, for example to the alpha of the thumb's background color.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(ScrollbarVisibility, Hovering)]
+pub struct ScrollbarAutoHide {
+    /// How long the scrollbar stays fully visible after it was last used, in seconds.
+    pub idle_timeout: f32,
+    /// How long it takes the scrollbar to fade out once the idle timeout has expired, in seconds.
+    pub fade_time: f32,
+}
+
+impl Default for ScrollbarAutoHide {
+    fn default() -> Self {
+        Self {
+            idle_timeout: 1.0,
+            fade_time: 0.3,
+        }
+    }
+}
+
+/// Visibility state of an auto-hiding scrollbar, which is updated by [`ScrollbarAutoHide`].
+/// This only counts as changed when the scrollbar's visibility does, so styling systems can use
+/// `Changed<ScrollbarVisibility>` rather than checking it every frame.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ScrollbarVisibility {
+    /// Whether the content is larger than the visible area.
+    scrollable: bool,
+    /// Opacity of the scrollbar, from 0 (hidden) to 1 (fully visible).
+    opacity: f32,
+    /// Time since the scrollbar was last used, in seconds.
+    idle: f32,
+    /// Scroll offset when the visibility was last updated.
+    last_offset: f32,
+}
+
+impl ScrollbarVisibility {
+    /// Whether the target's content is larger than its visible area, so that there is
+    /// something to scroll.
+    pub fn is_scrollable(&self) -> bool {
+        self.scrollable
+    }
+
+    /// Whether the scrollbar should be drawn at all.
+    pub fn is_visible(&self) -> bool {
+        self.opacity > 0.
+    }
+
+    /// The opacity of the scrollbar, from 0 (hidden) to 1 (fully visible). This is between 0
+    /// and 1 while the scrollbar is fading out.
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_scrollbar_visibility(
+    time: Res<Time>,
+    mut q_scrollbar: Query<(
+        &CoreScrollbar,
+        &ScrollbarAutoHide,
+        &ScrollbarDragState,
+        &Hovering,
+        Option<&Children>,
+        &mut ScrollbarVisibility,
+    )>,
+    q_step: Query<&ButtonPressed, With<CoreScrollbarStepButton>>,
+    q_scroll_area: Query<(&ScrollPosition, &ComputedNode)>,
+) {
+    for (scrollbar, auto_hide, drag, hovering, children, mut visibility) in q_scrollbar.iter_mut() {
+        let Ok((scroll_pos, scroll_content)) = q_scroll_area.get(scrollbar.target) else {
+            continue;
+        };
+
+        let orientation = scrollbar.orientation;
+        let visible_size = orientation.axis(scroll_content.size());
+        let content_size = orientation.axis(scroll_content.content_size());
+        let offset = scroll_offset(orientation, scroll_pos);
+        let in_use = hovering.0
+            || drag.is_pressed()
+            || q_step
+                .iter_many(children.into_iter().flatten())
+                .any(|pressed| pressed.0);
+
+        // Track the idle time without marking the component as changed.
+        let state = visibility.bypass_change_detection();
+        let (old_scrollable, old_opacity) = (state.scrollable, state.opacity);
+        state.scrollable = content_size > visible_size;
+        if in_use || offset != state.last_offset {
+            state.idle = 0.;
+        } else {
+            state.idle += time.delta_secs();
+        }
+        state.last_offset = offset;
+
+        state.opacity = if !state.scrollable {
+            0.
+        } else if state.idle <= auto_hide.idle_timeout {
+            1.
+        } else if auto_hide.fade_time > 0. {
+            (1. - (state.idle - auto_hide.idle_timeout) / auto_hide.fade_time).max(0.)
+        } else {
+            0.
+        };
+
+        if state.scrollable != old_scrollable || state.opacity != old_opacity {
+            visibility.set_changed();
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::{fmt::Debug, sync::Arc};
+
+/// Trait for user-defined mappings between slider values and thumb positions. Positions are
+/// normalized, so that `0` corresponds to `min` and `1` corresponds to `max`. Values are passed
+/// as `f64` regardless of the slider's value type.
+///
+/// Implementations should accept positions and values outside of the slider range, since drag
+/// gestures can overshoot the ends of the slider.
+pub trait ValueMapping: Debug + Send + Sync + 'static {
+    /// Convert a slider value to a normalized thumb position.
+    fn to_position(&self, value: f64, min: f64, max: f64) -> f64;
+
+    /// Convert a normalized thumb position to a slider value. This should be the inverse of
+    /// [`ValueMapping::to_position`].
+    fn to_value(&self, position: f64, min: f64, max: f64) -> f64;
+}
+
+/// Determines how the value of a [`CoreSlider`](crate::CoreSlider) relates to the position of
+/// its thumb. The mapping is applied consistently to dragging, track clicks, keyboard stepping
+/// and the reported thumb position.
+#[derive(Debug, Clone, Default)]
+pub enum SliderMapping {
+    /// The value changes in proportion to the thumb position.
+    #[default]
+    Linear,
+    /// Equal thumb movements multiply the value by equal ratios. This is useful for frequency,
+    /// gain and zoom controls. Both ends of the slider range must be greater than zero; otherwise
+    /// the mapping falls back to [`SliderMapping::Linear`].
+    Logarithmic,
+    /// The value changes in proportion to the thumb position raised to the given power. Powers
+    /// greater than 1 give finer control near the minimum of the range.
+    Power(f32),
+    /// A user-defined mapping.
+    Custom(Arc<dyn ValueMapping>),
+}
+
+impl SliderMapping {
+    fn is_valid_log_range(min: f64, max: f64) -> bool {
+        min > 0. && max > 0.
+    }
+}
+
+impl ValueMapping for SliderMapping {
+    fn to_position(&self, value: f64, min: f64, max: f64) -> f64 {
+        match self {
+            SliderMapping::Logarithmic if Self::is_valid_log_range(min, max) => {
+                (value.max(f64::MIN_POSITIVE) / min).ln() / (max / min).ln()
+            }
+            SliderMapping::Power(exponent) if *exponent > 0. => {
+                let linear = (value - min) / (max - min);
+                linear.signum() * linear.abs().powf(f64::from(*exponent).recip())
+            }
+            SliderMapping::Custom(mapping) => mapping.to_position(value, min, max),
+            _ => (value - min) / (max - min),
+        }
+    }
+
+    fn to_value(&self, position: f64, min: f64, max: f64) -> f64 {
+        match self {
+            SliderMapping::Logarithmic if Self::is_valid_log_range(min, max) => {
+                min * (max / min).powf(position)
+            }
+            SliderMapping::Power(exponent) if *exponent > 0. => {
+                min + (max - min) * position.signum() * position.abs().powf(f64::from(*exponent))
+            }
+            SliderMapping::Custom(mapping) => mapping.to_value(position, min, max),
+            _ => min + (max - min) * position,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+/// Maximum number of tick marks which will be generated for [`SliderSnap::Increment`]. Sliders
+/// whose range contains more increments than this don't report any ticks.
+pub(crate) const MAX_INCREMENT_TICKS: usize = 1000;
+
+/// Determines how the values emitted by a [`CoreSlider`](crate::CoreSlider) are quantized.
+/// Snapping is applied before the value is passed to `on_change` or emitted as a
+/// [`ValueChange`](crate::ValueChange) event. The type parameter is the slider's value type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum SliderSnap<T = f32> {
+    /// Values are not snapped.
+    #[default]
+    None,
+    /// Values are snapped to the nearest multiple of the slider's `increment`, counting from
+    /// the slider's `min`.
+    Increment,
+    /// Values are snapped to the nearest detent in the list. The arrow and page keys move to
+    /// the next detent in the direction of travel.
+    Detents(Vec<T>),
+    /// Values are snapped to the nearest detent in the list, but only when the thumb is within
+    /// `radius` of it. The radius is measured in normalized thumb positions, so a radius of
+    /// `0.02` is 2% of the length of the slider. Keyboard input is not snapped.
+    Magnetic { detents: Vec<T>, radius: f32 },
+}
+
+/// Returns the detent closest to `value`, if there are any.
+pub(crate) fn nearest_detent(detents: impl IntoIterator<Item = f64>, value: f64) -> Option<f64> {
+    detents
+        .into_iter()
+        .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+}
+
+/// Returns the closest detent that is strictly above or below `value`, depending on the sign of
+/// `direction`.
+pub(crate) fn next_detent(
+    detents: impl IntoIterator<Item = f64>,
+    value: f64,
+    direction: f64,
+) -> Option<f64> {
+    let detents = detents.into_iter();
+    if direction > 0. {
+        detents
+            .filter(|detent| *detent > value)
+            .min_by(f64::total_cmp)
+    } else {
+        detents
+            .filter(|detent| *detent < value)
+            .max_by(f64::total_cmp)
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt::Debug;
+
+/// Trait for numeric types which can be used as the value of a
+/// [`CoreSlider`](crate::CoreSlider). The slider performs its calculations in `f64`, and converts
+/// the results back to the value type before they are emitted.
+///
+/// The trait is implemented for `f32`, `f64`, `i32`, `i64` and `u32`, and can be implemented for
+/// other types. Sliders of each value type need their own
+/// [`CoreSliderPlugin`](crate::CoreSliderPlugin).
+pub trait SliderValue: Copy + Debug + Default + PartialOrd + Send + Sync + 'static {
+    /// Whether the type can only represent whole numbers. Fine keyboard steps on integer
+    /// sliders never move by less than one.
+    const INTEGER: bool;
+
+    /// Convert the value to an `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Convert an `f64` to the value type. Integer types round to the nearest whole number, and
+    /// saturate at the limits of the type.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_float_slider_value {
+    ($($ty:ty),*) => {
+        $(
+            impl SliderValue for $ty {
+                const INTEGER: bool = false;
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $ty
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_integer_slider_value {
+    ($($ty:ty),*) => {
+        $(
+            impl SliderValue for $ty {
+                const INTEGER: bool = true;
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value.round() as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_float_slider_value!(f32, f64);
+impl_integer_slider_value!(i32, i64, u32);
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+/// Determines what happens when the user presses on the track of a slider or scrollbar, outside
+/// of the thumb.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TrackClick {
+    /// Move one page toward the pointer. Scrollbars keep paging while the button is held, until
+    /// the thumb reaches the pointer.
+    #[default]
+    Page,
+    /// Jump directly to the pointer position, and continue dragging from there.
+    Jump,
+}
+
+impl TrackClick {
+    /// Returns the opposite behavior. This is used when the user holds down the Alt (Option)
+    /// key while pressing on the track, as on macOS and GTK.
+    pub fn inverted(self) -> Self {
+        match self {
+            TrackClick::Page => TrackClick::Jump,
+            TrackClick::Jump => TrackClick::Page,
+        }
+    }
+}
+
+/// Convert a pointer position, in logical window coordinates, to a position relative to the
+/// top-left corner of a UI node, in logical pixels. This assumes that the UI camera's viewport
+/// starts at the window origin.
+pub(crate) fn pointer_local_position(
+    node: &ComputedNode,
+    transform: &GlobalTransform,
+    pointer_position: Vec2,
+) -> Vec2 {
+    let top_left = transform.translation().truncate() - node.size() * 0.5;
+    pointer_position - top_left * node.inverse_scale_factor
+}